@@ -0,0 +1,47 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Select(usize),
+}
+
+#[derive(Default)]
+struct Settings {
+    selected: usize,
+}
+
+impl Component for Settings {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let panel = |text: &str| Node::native("p").with_child(Node::text(text));
+
+        // Tab into the list, then Left, Right, Home and End switch tabs.
+        slick::tabs(
+            "settings",
+            vec![
+                ("Profile".to_string(), panel("Your name and picture.")),
+                (
+                    "Notifications".to_string(),
+                    Node::native("label")
+                        .with_child(Node::native("input").type_("checkbox"))
+                        .with_child(Node::text(" Email me about replies")),
+                ),
+                ("Privacy".to_string(), panel("Who can see what you post.")),
+            ],
+            self.selected,
+            Message::Select,
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Select(i) => self.selected = i,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(Settings::default(), "body");
+}
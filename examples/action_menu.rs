@@ -0,0 +1,72 @@
+use slick::{roving, Component, Effect, Modifiers, Node};
+
+const ACTIONS: [&str; 4] = ["New file", "Open…", "Save", "Close"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Move(usize),
+    Choose,
+}
+
+#[derive(Default)]
+struct App {
+    active: usize,
+    last_action: Option<&'static str>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let items = ACTIONS
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                Node::native("li")
+                    .with_attribute("role", "menuitem")
+                    .with_style("padding", "4px 16px")
+                    .with_style("cursor", "pointer")
+                    .with_style(
+                        "background",
+                        if i == self.active { "#def" } else { "white" },
+                    )
+                    .with_child(Node::text(*action))
+                    .on_click(Message::Move(i))
+            })
+            .collect();
+
+        let menu = Node::native("ul")
+            .with_attribute("role", "menu")
+            .with_attribute("aria-label", "File")
+            .with_style("width", "160px")
+            .with_style("padding", "4px 0")
+            .with_style("list-style", "none")
+            .with_style("box-shadow", "0 2px 8px #0004")
+            .on_key("Enter", Modifiers::NONE, || Message::Choose)
+            .on_key(" ", Modifiers::NONE, || Message::Choose);
+
+        Node::native("main")
+            .with_child(Node::native("p").with_child(Node::text(
+                "Tab into the menu, move with the arrow keys, Home and End, choose with Enter.",
+            )))
+            .with_child(roving(menu, items, self.active, Message::Move))
+            .with_child(
+                Node::native("p").with_child(Node::text(match self.last_action {
+                    Some(action) => format!("Last action: {}", action),
+                    None => "No action yet".to_string(),
+                })),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Move(index) => self.active = index,
+            Message::Choose => self.last_action = Some(ACTIONS[self.active]),
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,69 @@
+use slick::{Component, Effect, Node};
+
+const PLAYER: &str = "#player";
+
+#[derive(Debug, Clone)]
+enum Message {
+    Play,
+    Pause,
+    Restart,
+}
+
+#[derive(Default)]
+struct App {
+    playing: bool,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let toggle = if self.playing {
+            Node::native("button")
+                .with_child(Node::text("Pause"))
+                .on_click(Message::Pause)
+        } else {
+            Node::native("button")
+                .with_child(Node::text("Play"))
+                .on_click(Message::Play)
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("audio")
+                    .with_attribute("id", "player")
+                    .with_attribute(
+                        "src",
+                        "https://upload.wikimedia.org/wikipedia/commons/c/c8/Example.ogg",
+                    )
+                    .with_attribute("preload", "metadata"),
+            )
+            .with_child(toggle)
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Restart"))
+                    .on_click(Message::Restart),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        Some(match msg {
+            Message::Play => {
+                self.playing = true;
+                Effect::MediaPlay(PLAYER.to_string())
+            }
+            Message::Pause => {
+                self.playing = false;
+                Effect::MediaPause(PLAYER.to_string())
+            }
+            Message::Restart => Effect::MediaSeek {
+                selector: PLAYER.to_string(),
+                seconds: 0.0,
+            },
+        })
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,89 @@
+use slick::{http::Progress, Component, Effect, EffectError, Node, WebFile};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(Vec<WebFile>),
+    Upload,
+    Progress(Progress),
+    Uploaded(Result<String, EffectError>),
+}
+
+#[derive(Default)]
+struct App {
+    avatar: Option<WebFile>,
+    progress: Option<f64>,
+    status: String,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let mut main = Node::native("main")
+            .with_child(
+                Node::native("input")
+                    .with_attribute("type", "file")
+                    .with_attribute("accept", "image/*")
+                    .on_files(Message::Selected),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Upload"))
+                    .on_click(Message::Upload),
+            );
+
+        if let Some(progress) = self.progress {
+            main = main.with_child(
+                Node::native("progress")
+                    .with_attribute("max", 100)
+                    .with_attribute("value", (progress * 100.0) as i32),
+            );
+        }
+
+        main.with_child(Node::native("p").with_child(Node::text(&self.status)))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Selected(files) => self.avatar = files.into_iter().next(),
+            Message::Upload => {
+                let avatar = match &self.avatar {
+                    Some(avatar) => avatar.clone(),
+                    None => {
+                        self.status = "Pick an image first".to_string();
+                        return None;
+                    }
+                };
+
+                self.progress = Some(0.0);
+                self.status = format!("Uploading {}", avatar.name);
+
+                return Some(Effect::Upload {
+                    url: "/api/avatar".to_string(),
+                    fields: vec![("user".to_string(), "me".to_string())],
+                    files: vec![("avatar".to_string(), avatar)],
+                    on_progress: Some(Box::new(|progress| Box::new(Message::Progress(progress)))),
+                    on_uploaded: Box::new(|result| Box::new(Message::Uploaded(result))),
+                });
+            }
+            Message::Progress(progress) => {
+                if let Some(fraction) = progress.fraction() {
+                    self.progress = Some(fraction);
+                }
+            }
+            Message::Uploaded(result) => {
+                self.progress = None;
+                self.status = match result {
+                    Ok(_) => "Avatar updated".to_string(),
+                    Err(EffectError::Network) => "Upload failed, are you offline?".to_string(),
+                    Err(error) => format!("Upload failed: {}", error),
+                };
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
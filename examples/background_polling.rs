@@ -0,0 +1,72 @@
+use slick::{Component, Effect, Node, Subscription};
+
+const POLL_MS: i32 = 2000;
+
+#[derive(Debug)]
+enum Message {
+    /// Carries the polling loop it belongs to, so a loop that was paused and
+    /// replaced doesn't keep going.
+    Poll(u32),
+    PageVisible(bool),
+}
+
+#[derive(Default)]
+struct App {
+    polls: u32,
+    /// Bumped whenever polling stops or starts again.
+    generation: u32,
+    paused: bool,
+}
+
+impl App {
+    fn schedule(&self) -> Effect {
+        Effect::Delay {
+            ms: POLL_MS,
+            msg: Box::new(Message::Poll(self.generation)),
+        }
+    }
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("main").with_child(Node::native("p").with_child(Node::text(format!(
+            "Polled {} times, {}",
+            self.polls,
+            if self.paused {
+                "paused while the tab is hidden"
+            } else {
+                "polling every 2 seconds"
+            }
+        ))))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Poll(generation) if generation == self.generation && !self.paused => {
+                // A real app would fetch here.
+                self.polls += 1;
+                Some(self.schedule())
+            }
+            Message::Poll(_) => None,
+            Message::PageVisible(visible) => {
+                self.paused = !visible;
+                self.generation += 1;
+                // Catches up right away instead of waiting a whole interval.
+                visible.then(|| Effect::Delay {
+                    ms: 0,
+                    msg: Box::new(Message::Poll(self.generation)),
+                })
+            }
+        }
+    }
+
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::on_visibility_change(Message::PageVisible)]
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body").dispatch(Message::Poll(0));
+}
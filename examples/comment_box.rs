@@ -0,0 +1,60 @@
+use slick::{Component, Effect, Modifiers, Node};
+
+#[derive(Debug)]
+enum Message {
+    Edit(String),
+    Submit,
+}
+
+#[derive(Default)]
+struct App {
+    draft: String,
+    comments: Vec<String>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let comments = self
+            .comments
+            .iter()
+            .fold(Node::native("ul"), |list, comment| {
+                list.with_child(
+                    Node::native("li")
+                        .with_style("white-space", "pre-wrap")
+                        .with_child(Node::text(comment.as_str())),
+                )
+            });
+
+        Node::native("main").with_child(comments).with_child(
+            // Plain Enter isn't bound, so it inserts a newline as usual.
+            Node::native("textarea")
+                .with_attribute("rows", 4)
+                .with_attribute("cols", 60)
+                .with_attribute("placeholder", "Ctrl + Enter to send")
+                .with_value(self.draft.as_str())
+                .on_input(Message::Edit)
+                .on_key("Enter", Modifiers::CTRL, || Message::Submit)
+                .on_key("Enter", Modifiers::META, || Message::Submit),
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(draft) => self.draft = draft,
+            Message::Submit => {
+                let comment = self.draft.trim();
+                if !comment.is_empty() {
+                    self.comments.push(comment.to_string());
+                }
+                self.draft.clear();
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,48 @@
+use slick::{Component, Effect, Node};
+
+/// Ships its own stylesheet, which ends up in the head once, no matter how
+/// many badges are shown.
+struct Badge {
+    label: &'static str,
+}
+
+impl Component for Badge {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        Node::native("span")
+            .with_attribute("class", "badge")
+            .with_child(Node::text(self.label))
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+
+    fn css(&self) -> &str {
+        ".badge { display: inline-block; margin: 0 4px; padding: 2px 8px; \
+         border-radius: 999px; background: #e0e7ff; color: #3730a3; font-size: 12px }"
+    }
+}
+
+struct App;
+
+impl Component for App {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        ["rust", "wasm", "web", "ui", "virtual dom"]
+            .iter()
+            .fold(Node::native("p"), |tags, &label| {
+                tags.with_child(Node::custom(Box::new(Badge { label })))
+            })
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+fn main() {
+    slick::run(App, "body");
+}
@@ -0,0 +1,84 @@
+use slick::{css::px, Component, Effect, Node};
+
+const ACTIONS: [&str; 3] = ["Rename", "Duplicate", "Delete"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    OpenMenu((i32, i32)),
+    CloseMenu,
+    Choose(&'static str),
+}
+
+#[derive(Default)]
+struct App {
+    menu: Option<(i32, i32)>,
+    last_action: Option<&'static str>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let file = Node::native("div")
+            .with_style("padding", "16px")
+            .with_style("border", "1px dashed #999")
+            .with_style("width", "200px")
+            .with_child(Node::text("📄 report.pdf (right-click me)"))
+            .on_context_menu(Message::OpenMenu);
+
+        let mut main = Node::native("main")
+            .with_style("min-height", "100vh")
+            .with_child(file)
+            .with_child(
+                Node::native("p").with_child(Node::text(match self.last_action {
+                    Some(action) => format!("Last action: {}", action),
+                    None => "No action yet".to_string(),
+                })),
+            )
+            .on_click(Message::CloseMenu);
+
+        if let Some((x, y)) = self.menu {
+            let menu = ACTIONS.iter().fold(
+                Node::native("ul")
+                    .with_attribute("role", "menu")
+                    .with_style("position", "fixed")
+                    .with_style("left", px(x))
+                    .with_style("top", px(y))
+                    .with_style("margin", "0")
+                    .with_style("padding", "4px 0")
+                    .with_style("list-style", "none")
+                    .with_style("background", "white")
+                    .with_style("box-shadow", "0 2px 8px #0004"),
+                |menu, &action| {
+                    menu.with_child(
+                        Node::native("li")
+                            .with_attribute("role", "menuitem")
+                            .with_style("padding", "4px 16px")
+                            .with_style("cursor", "pointer")
+                            .with_child(Node::text(action))
+                            .on_click(Message::Choose(action)),
+                    )
+                },
+            );
+            main = main.with_child(menu);
+        }
+
+        main
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::OpenMenu(position) => self.menu = Some(position),
+            Message::CloseMenu => self.menu = None,
+            Message::Choose(action) => {
+                self.last_action = Some(action);
+                self.menu = None;
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
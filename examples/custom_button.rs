@@ -0,0 +1,49 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Like,
+    ToggleNotify,
+}
+
+#[derive(Default)]
+struct Post {
+    likes: u32,
+    notify: bool,
+}
+
+impl Component for Post {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        // Both can be tabbed to and used with the keyboard, Enter or Space
+        // like, Space toggles the checkbox, like with the native elements.
+        Node::native("div")
+            .with_child(
+                Node::native("div")
+                    .with_attribute("class", "like-button")
+                    .with_child(Node::text(format!("♥ {}", self.likes)))
+                    .on_click(Message::Like)
+                    .button_role(),
+            )
+            .with_child(
+                Node::native("span")
+                    .with_attribute("class", "switch")
+                    .with_child(Node::text("Notify me about replies"))
+                    .on_click(Message::ToggleNotify)
+                    .checkbox_role(self.notify),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Like => self.likes += 1,
+            Message::ToggleNotify => self.notify = !self.notify,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(Post::default(), "body");
+}
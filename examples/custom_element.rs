@@ -0,0 +1,55 @@
+//! Defines `<my-counter>`, which works from plain HTML once the module is
+//! loaded:
+//!
+//! ```html
+//! <my-counter start="5"></my-counter>
+//! <my-counter label="Clicks"></my-counter>
+//! ```
+
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Increment,
+    Start(i32),
+    Label(String),
+}
+
+struct Counter {
+    count: i32,
+    label: String,
+}
+
+impl Component for Counter {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("button")
+            .with_child(Node::text(format!("{}: {}", self.label, self.count)))
+            .on_click(Message::Increment)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Increment => self.count += 1,
+            Message::Start(start) => self.count = start,
+            Message::Label(label) => self.label = label,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::define_element(
+        "my-counter",
+        &["start", "label"],
+        || Counter {
+            count: 0,
+            label: "Count".to_string(),
+        },
+        |name, value| match name {
+            "start" => Message::Start(value.and_then(|value| value.parse().ok()).unwrap_or(0)),
+            _ => Message::Label(value.unwrap_or_else(|| "Count".to_string())),
+        },
+    );
+}
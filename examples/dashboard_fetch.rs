@@ -0,0 +1,99 @@
+use slick::{Component, Effect, Node};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
+
+#[derive(Debug)]
+enum Message {
+    Load,
+    Loaded(Result<(String, String), String>),
+}
+
+#[derive(Default)]
+struct Dashboard {
+    loading: bool,
+    result: Option<Result<(String, String), String>>,
+}
+
+/// Starts fetching `url`, the body is read once the result is awaited.
+fn fetch(url: &str) -> JsFuture {
+    JsFuture::from(web_sys::window().unwrap().fetch_with_str(url))
+}
+
+async fn text(request: JsFuture) -> Result<String, String> {
+    let response: Response = request
+        .await
+        .map_err(|_| "the request failed".to_string())?
+        .unchecked_into();
+
+    if !response.ok() {
+        return Err(format!("the server responded with {}", response.status()));
+    }
+
+    let body = response.text().map_err(|_| "the body can't be read")?;
+    JsFuture::from(body)
+        .await
+        .ok()
+        .and_then(|body| body.as_string())
+        .ok_or_else(|| "the body isn't text".to_string())
+}
+
+impl Component for Dashboard {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let content = match (&self.result, self.loading) {
+            (_, true) => Node::text("Loading…"),
+            (None, false) => Node::text("Nothing loaded yet"),
+            (Some(Ok((profile, stats))), false) => Node::native("dl")
+                .with_child(Node::native("dt").with_child(Node::text("Profile")))
+                .with_child(Node::native("dd").with_child(Node::text(profile.as_str())))
+                .with_child(Node::native("dt").with_child(Node::text("Stats")))
+                .with_child(Node::native("dd").with_child(Node::text(stats.as_str()))),
+            (Some(Err(error)), false) => Node::native("p")
+                .with_attribute("role", "alert")
+                .with_child(Node::text(format!(
+                    "Couldn't load the dashboard: {}",
+                    error
+                ))),
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("button")
+                    .with_attribute("disabled", self.loading)
+                    .with_child(Node::text("Load dashboard"))
+                    .on_click_with(|| Message::Load),
+            )
+            .with_child(content)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Load => {
+                self.loading = true;
+                Some(Effect::command(async {
+                    // Both requests are sent before either is awaited, so
+                    // they run at the same time.
+                    let profile = fetch("/api/profile");
+                    let stats = fetch("/api/stats");
+
+                    let result = match (text(profile).await, text(stats).await) {
+                        (Ok(profile), Ok(stats)) => Ok((profile, stats)),
+                        (Err(error), _) | (_, Err(error)) => Err(error),
+                    };
+                    Message::Loaded(result)
+                }))
+            }
+            Message::Loaded(result) => {
+                self.loading = false;
+                self.result = Some(result);
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(Dashboard::default(), "body");
+}
@@ -0,0 +1,63 @@
+use slick::{Component, Effect, Node};
+
+/// How long typing has to pause before the draft is saved.
+const DEBOUNCE_MS: i32 = 800;
+
+#[derive(Debug)]
+enum Message {
+    Edit(String),
+    /// Carries the edit it was scheduled for, so delays that were overtaken
+    /// by newer edits can be told apart.
+    Save(u32),
+}
+
+#[derive(Default)]
+struct App {
+    draft: String,
+    edits: u32,
+    saved: Option<String>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let status = match &self.saved {
+            Some(saved) if *saved == self.draft => "All changes saved".to_string(),
+            Some(saved) => format!("Unsaved changes, last saved {} characters", saved.len()),
+            None => "Not saved yet".to_string(),
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("textarea")
+                    .with_attribute("rows", 8)
+                    .with_attribute("cols", 60)
+                    .with_value(self.draft.as_str())
+                    .on_input(Message::Edit),
+            )
+            .with_child(Node::native("p").with_child(Node::text(status)))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(draft) => {
+                self.draft = draft;
+                self.edits += 1;
+
+                return Some(Effect::Delay {
+                    ms: DEBOUNCE_MS,
+                    msg: Box::new(Message::Save(self.edits)),
+                });
+            }
+            // Only the delay of the latest edit saves.
+            Message::Save(edit) if edit == self.edits => self.saved = Some(self.draft.clone()),
+            Message::Save(_) => {}
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
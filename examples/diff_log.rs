@@ -0,0 +1,45 @@
+use slick::{diff::describe_diff, Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+}
+
+#[derive(Default)]
+struct Light {
+    on: bool,
+}
+
+impl Component for Light {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let (class, label) = if self.on {
+            ("on", "On")
+        } else {
+            ("off", "Off")
+        };
+
+        Node::native("div")
+            .with_child(Node::native("span").with_attribute("class", class))
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text(label))
+                    .on_click(Message::Toggle),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        // Rendered around the change only to see what it patches, like
+        // `SetAttribute on div[0]/span[0]: class "off" → "on"`.
+        let before = self.view();
+        match msg {
+            Message::Toggle => self.on = !self.on,
+        }
+        Some(Effect::Log(describe_diff(&before, &self.view())))
+    }
+}
+
+fn main() {
+    slick::run(Light::default(), "body");
+}
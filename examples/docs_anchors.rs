@@ -0,0 +1,84 @@
+use slick::{
+    router::{self, Route, Router},
+    Component, Effect, Node,
+};
+
+const SECTIONS: [(&str, &str); 4] = [
+    ("installation", "Installation"),
+    ("getting-started", "Getting started"),
+    ("routing", "Routing"),
+    ("effects", "Effects"),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Open(&'static str),
+}
+
+struct App {
+    router: Router<App>,
+}
+
+fn docs() -> Node {
+    let toc = SECTIONS
+        .iter()
+        .fold(Node::native("ul"), |toc, &(id, title)| {
+            toc.with_child(
+                Node::native("li").with_child(
+                    Node::native("a")
+                        .with_attribute("href", format!("#{}", id))
+                        .with_child(Node::text(title)),
+                ),
+            )
+        });
+
+    SECTIONS.iter().fold(
+        Node::native("article").with_child(toc),
+        |article, &(id, title)| {
+            article.with_child(
+                Node::native("section")
+                    .with_attribute("id", id)
+                    .with_style("min-height", "100vh")
+                    .with_child(Node::native("h2").with_child(Node::text(title)))
+                    .with_child(Node::text(format!("All about {}.", title.to_lowercase()))),
+            )
+        },
+    )
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let nav = Node::native("nav").with_child(
+            Node::native("button")
+                .with_child(Node::text("Jump to the routing docs"))
+                .on_click(Message::Open("/docs#routing")),
+        );
+
+        Node::native("main").with_child(nav).with_child(
+            self.router
+                .view(self, &router::current_path())
+                .unwrap_or_else(|| Node::text("Not found")),
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            // Scrolls to `#routing` once the docs are rendered.
+            Message::Open(path) => Some(Effect::Navigate(path.to_string())),
+        }
+    }
+}
+
+fn main() {
+    // Loading `/docs#effects` directly lands on that section too.
+    slick::run(
+        App {
+            router: Router::new()
+                .with_route(Route::new("/", |_, _| Node::text("Welcome!")))
+                .with_route(Route::new("/docs", |_, _| docs())),
+        },
+        "body",
+    );
+}
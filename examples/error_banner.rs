@@ -0,0 +1,118 @@
+use slick::{Component, Effect, EffectError, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(String),
+    Save,
+    Saved(Result<String, EffectError>),
+    Copy,
+    Copied(Result<(), EffectError>),
+    Dismiss,
+}
+
+#[derive(Default)]
+struct App {
+    note: String,
+    status: Option<&'static str>,
+    /// The last failure of any effect.
+    error: Option<EffectError>,
+}
+
+impl App {
+    /// Every effect reports failures the same way, so one place handles them.
+    fn finished<T>(&mut self, result: Result<T, EffectError>, done: &'static str) {
+        match result {
+            Ok(_) => {
+                self.status = Some(done);
+                self.error = None;
+            }
+            Err(error) => {
+                self.status = None;
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let mut main = Node::native("main");
+
+        if let Some(error) = &self.error {
+            let hint = match error {
+                EffectError::Network => "Check your connection and try again.",
+                EffectError::PermissionDenied => "You aren't allowed to do that.",
+                _ => "Try again later.",
+            };
+            main = main.with_child(
+                Node::native("div")
+                    .with_attribute("role", "alert")
+                    .with_style("padding", "8px 12px")
+                    .with_style("background", "#fdd")
+                    .with_style("border", "1px solid #c66")
+                    .with_child(Node::text(format!(
+                        "Something went wrong: {}. {} ",
+                        error, hint
+                    )))
+                    .with_child(
+                        Node::native("button")
+                            .with_child(Node::text("Dismiss"))
+                            .on_click(Message::Dismiss),
+                    ),
+            );
+        }
+
+        main.with_child(
+            Node::native("textarea")
+                .with_attribute("rows", 6)
+                .with_attribute("cols", 60)
+                .with_value(self.note.as_str())
+                .on_input(Message::Edit),
+        )
+        .with_child(
+            Node::native("p")
+                .with_child(
+                    Node::native("button")
+                        .with_child(Node::text("Save"))
+                        .on_click(Message::Save),
+                )
+                .with_child(
+                    Node::native("button")
+                        .with_child(Node::text("Copy"))
+                        .on_click(Message::Copy),
+                ),
+        )
+        .with_child(Node::native("p").with_child(Node::text(self.status.unwrap_or(""))))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(note) => self.note = note,
+            Message::Save => {
+                return Some(Effect::Upload {
+                    url: "/api/notes".to_string(),
+                    fields: vec![("note".to_string(), self.note.clone())],
+                    files: vec![],
+                    on_progress: None,
+                    on_uploaded: Box::new(|result| Box::new(Message::Saved(result))),
+                })
+            }
+            Message::Saved(result) => self.finished(result, "Saved"),
+            Message::Copy => {
+                return Some(Effect::CopyToClipboard(
+                    self.note.clone(),
+                    Box::new(|result| Box::new(Message::Copied(result))),
+                ))
+            }
+            Message::Copied(result) => self.finished(result, "Copied"),
+            Message::Dismiss => self.error = None,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
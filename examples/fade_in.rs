@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use slick::{Component, Effect, Node};
+
+const STYLE: &str = "
+.card { height: 200px; margin: 16px; background: #eee; opacity: 0; transition: opacity 600ms; }
+.card.seen { opacity: 1; }
+";
+
+#[derive(Debug)]
+enum Message {
+    VisibilityChanged(usize, bool),
+}
+
+#[derive(Default)]
+struct App {
+    seen: HashSet<usize>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        (0..30).fold(
+            Node::native("main").with_child(Node::native("style").with_child(Node::text(STYLE))),
+            |main, i| {
+                let card = Node::native("div").with_child(Node::text(format!("Card #{}", i + 1)));
+
+                // Seen cards stay faded in, so they don't need the observer
+                // anymore.
+                main.with_child(if self.seen.contains(&i) {
+                    card.with_attribute("class", "card seen")
+                } else {
+                    card.with_attribute("class", "card")
+                        .on_intersect(0.5, move |visible| {
+                            Message::VisibilityChanged(i, visible)
+                        })
+                })
+            },
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::VisibilityChanged(i, true) => {
+                self.seen.insert(i);
+            }
+            Message::VisibilityChanged(_, false) => {}
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,78 @@
+use slick::{Component, Effect, EffectError, Node, WebFile};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(Vec<WebFile>),
+    Preview(usize),
+    Loaded(Result<String, EffectError>),
+}
+
+#[derive(Default)]
+struct App {
+    files: Vec<WebFile>,
+    preview: Option<String>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let files = self
+            .files
+            .iter()
+            .enumerate()
+            .fold(Node::native("ul"), |list, (i, file)| {
+                list.with_child(
+                    Node::native("li")
+                        .with_child(Node::text(format!(
+                            "{} ({} bytes, {})",
+                            file.name,
+                            file.size,
+                            if file.type_.is_empty() {
+                                "unknown type"
+                            } else {
+                                &file.type_
+                            }
+                        )))
+                        .on_click(Message::Preview(i)),
+                )
+            });
+
+        Node::native("main")
+            .with_child(
+                Node::native("input")
+                    .with_attribute("type", "file")
+                    .with_attribute("multiple", "")
+                    .on_files(Message::Selected),
+            )
+            .with_child(files)
+            .with_child(
+                Node::native("pre").with_child(Node::text(
+                    self.preview
+                        .as_deref()
+                        .unwrap_or("Click a file to preview it"),
+                )),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Selected(files) => self.files = files,
+            Message::Preview(i) => {
+                return self.files.get(i).map(|file| Effect::ReadFileText {
+                    file: file.clone(),
+                    on_load: Box::new(|text| Box::new(Message::Loaded(text))),
+                })
+            }
+            Message::Loaded(Ok(text)) => self.preview = Some(text),
+            Message::Loaded(Err(error)) => {
+                self.preview = Some(format!("Couldn't read the file: {}", error))
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,58 @@
+use slick::{router, Component, Effect, Node};
+
+const LANGUAGES: [&str; 8] = [
+    "Ada", "C", "Elixir", "Go", "Haskell", "OCaml", "Rust", "Zig",
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Filter(String),
+}
+
+struct App {
+    filter: String,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let filter = self.filter.to_lowercase();
+        let list = LANGUAGES
+            .iter()
+            .filter(|language| language.to_lowercase().contains(&filter))
+            .fold(Node::native("ul"), |list, language| {
+                list.with_child(Node::native("li").with_child(Node::text(*language)))
+            });
+
+        Node::native("main")
+            .with_child(
+                Node::native("input")
+                    .with_attribute("type", "search")
+                    .with_attribute("aria-label", "Filter languages")
+                    .with_value(self.filter.as_str())
+                    .on_input(Message::Filter),
+            )
+            .with_child(list)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        let Message::Filter(filter) = msg;
+        // Replaced instead of pushed, so typing doesn't add an entry for every
+        // keystroke and reloading keeps the filter.
+        let path = if filter.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/?q={}", js_sys::encode_uri_component(&filter))
+        };
+        self.filter = filter;
+        Some(Effect::ReplaceUrl(path))
+    }
+}
+
+fn main() {
+    let filter = router::parse_query(&router::current_path())
+        .remove("q")
+        .unwrap_or_default();
+    slick::run(App { filter }, "body");
+}
@@ -0,0 +1,78 @@
+use slick::{Component, Effect, EffectError, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(String),
+    Send,
+    Sent(Result<String, EffectError>),
+}
+
+#[derive(Default)]
+struct App {
+    feedback: String,
+    sending: bool,
+    status: String,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let mut send = Node::native("button")
+            .with_child(Node::text("Send feedback"))
+            .on_click(Message::Send);
+        if self.sending {
+            send = send.with_attribute("disabled", true);
+        }
+
+        Node::native("main")
+            .with_child(
+                Node::native("textarea")
+                    .with_attribute("rows", 4)
+                    .with_attribute("cols", 60)
+                    .with_value(self.feedback.as_str())
+                    .on_input(Message::Edit),
+            )
+            .with_child(Node::native("p").with_child(send))
+            .with_child(Node::native("p").with_child(Node::text(&self.status)))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(feedback) => self.feedback = feedback,
+            Message::Send => {
+                self.sending = true;
+                self.status = "Sending…".to_string();
+
+                // Tried up to four times, waiting 0.5, 1 and 2 seconds in
+                // between. `Sent` only arrives once, with the final result.
+                return Some(Effect::retry(
+                    Effect::Upload {
+                        url: "/api/feedback".to_string(),
+                        fields: vec![("feedback".to_string(), self.feedback.clone())],
+                        files: vec![],
+                        on_progress: None,
+                        on_uploaded: Box::new(|result| Box::new(Message::Sent(result))),
+                    },
+                    3,
+                    500,
+                ));
+            }
+            Message::Sent(result) => {
+                self.sending = false;
+                self.status = match result {
+                    Ok(_) => {
+                        self.feedback.clear();
+                        "Thanks for your feedback!".to_string()
+                    }
+                    Err(error) => format!("Giving up: {}", error),
+                };
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,69 @@
+use slick::{
+    router::{self, Mode, Route, Router},
+    Component, Effect, Node,
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Navigate(&'static str),
+}
+
+struct App {
+    router: Router<App>,
+}
+
+fn link(href: &'static str, label: &str) -> Node {
+    // Plain links work too, the browser only changes the hash.
+    Node::native("a")
+        .with_attribute("href", format!("#{}", href))
+        .with_style("margin-right", "8px")
+        .with_child(Node::text(label))
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let page = self
+            .router
+            .view(self, &router::current_path())
+            .unwrap_or_else(|| Node::text("Not found"));
+
+        Node::native("main")
+            .with_child(
+                Node::native("nav")
+                    .with_child(link("/", "Home"))
+                    .with_child(link("/about", "About"))
+                    .with_child(
+                        Node::native("button")
+                            .with_child(Node::text("Open post 7"))
+                            .on_click(Message::Navigate("/posts/7")),
+                    ),
+            )
+            .with_child(page)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Navigate(path) => Some(Effect::Navigate(path.to_string())),
+        }
+    }
+}
+
+fn main() {
+    // Reloading `/#/about` works from any static file server, since only
+    // `/` is ever requested.
+    router::set_mode(Mode::Hash);
+
+    slick::run(
+        App {
+            router: Router::new()
+                .with_route(Route::new("/", |_, _| Node::text("Home")))
+                .with_route(Route::new("/about", |_, _| Node::text("About us")))
+                .with_route(Route::new("/posts/:id", |_, m| {
+                    Node::text(format!("Post {}", m.params["id"]))
+                })),
+        },
+        "body",
+    );
+}
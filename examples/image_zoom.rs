@@ -0,0 +1,83 @@
+use slick::{Component, Effect, Node, WheelInfo};
+
+const MIN_ZOOM: f64 = 0.25;
+const MAX_ZOOM: f64 = 8.0;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Wheel(WheelInfo),
+    Reset,
+}
+
+struct App {
+    zoom: f64,
+    pan: (f64, f64),
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let image = Node::native("img")
+            .with_attribute("src", "https://picsum.photos/id/1015/800/600")
+            .with_attribute("alt", "A river between mountains")
+            .with_attribute("draggable", "false")
+            .with_style("transform-origin", "center")
+            .with_style(
+                "transform",
+                format!(
+                    "translate({}px, {}px) scale({})",
+                    self.pan.0, self.pan.1, self.zoom
+                ),
+            );
+
+        Node::native("main")
+            .with_child(Node::native("p").with_child(Node::text(format!(
+                "Wheel to zoom ({:.0}%), shift + wheel to pan",
+                self.zoom * 100.0
+            ))))
+            .with_child(
+                // The page would scroll underneath otherwise.
+                Node::native("div")
+                    .with_style("width", "800px")
+                    .with_style("height", "600px")
+                    .with_style("overflow", "hidden")
+                    .with_style("border", "1px solid #999")
+                    .with_child(image)
+                    .on_wheel_prevent_default(Message::Wheel),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Reset"))
+                    .on_click(Message::Reset),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Wheel(wheel) if wheel.shift_key => {
+                // Browsers report shift + wheel as horizontal scrolling on
+                // some platforms, so take whichever delta moved.
+                self.pan.0 -= wheel.delta_x + wheel.delta_y;
+            }
+            Message::Wheel(wheel) => {
+                self.zoom = (self.zoom * (-wheel.delta_y / 500.0).exp()).clamp(MIN_ZOOM, MAX_ZOOM);
+            }
+            Message::Reset => {
+                self.zoom = 1.0;
+                self.pan = (0.0, 0.0);
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(
+        App {
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+        },
+        "body",
+    );
+}
@@ -0,0 +1,44 @@
+use slick::{infinite_scroll, Component, Effect, Node};
+
+const PAGE_SIZE: usize = 20;
+const PAGES: usize = 10;
+
+#[derive(Debug)]
+enum Message {
+    LoadMore,
+}
+
+#[derive(Default)]
+struct Feed {
+    posts: Vec<String>,
+}
+
+impl Component for Feed {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let posts = self.posts.iter().fold(Node::native("ul"), |list, post| {
+            list.with_child(Node::native("li").with_child(Node::text(post.as_str())))
+        });
+
+        infinite_scroll(posts, self.posts.len() < PAGE_SIZE * PAGES, || {
+            Message::LoadMore
+        })
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::LoadMore => {
+                // A real feed would fetch the next page here.
+                let start = self.posts.len();
+                self.posts
+                    .extend((start..start + PAGE_SIZE).map(|i| format!("Post #{}", i + 1)));
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(Feed::default(), "body");
+}
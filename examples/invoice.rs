@@ -0,0 +1,99 @@
+use slick::{Component, Effect, Node};
+
+const ITEMS: [(&str, u32, u32); 3] = [
+    ("Design workshop", 1, 120_000),
+    ("Development, hours", 24, 9_500),
+    ("Hosting, months", 12, 1_500),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Print,
+    Printed,
+}
+
+#[derive(Default)]
+struct Invoice {
+    printing: bool,
+}
+
+fn euros(cents: u32) -> String {
+    format!("€{}.{:02}", cents / 100, cents % 100)
+}
+
+impl Component for Invoice {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let rows = ITEMS
+            .iter()
+            .fold(Node::native("tbody"), |rows, &(item, quantity, price)| {
+                rows.with_child(
+                    Node::native("tr")
+                        .with_child(Node::native("td").with_child(Node::text(item)))
+                        .with_child(Node::native("td").with_child(Node::text(quantity.to_string())))
+                        .with_child(Node::native("td").with_child(Node::text(euros(price))))
+                        .with_child(
+                            Node::native("td").with_child(Node::text(euros(quantity * price))),
+                        ),
+                )
+            });
+        let total: u32 = ITEMS
+            .iter()
+            .map(|(_, quantity, price)| quantity * price)
+            .sum();
+
+        let invoice = Node::native("article")
+            .with_child(Node::native("h1").with_child(Node::text("Invoice #2024-017")))
+            .with_child(Node::native("table").with_child(rows))
+            .with_child(
+                Node::native("p")
+                    .with_style("font-weight", "bold")
+                    .with_child(Node::text(format!("Total: {}", euros(total)))),
+            );
+
+        // The print layout leaves out everything but the invoice.
+        if self.printing {
+            return Node::native("main")
+                .with_style("font-family", "serif")
+                .with_child(invoice);
+        }
+
+        Node::native("main")
+            .with_style("font-family", "sans-serif")
+            .with_style("max-width", "640px")
+            .with_child(
+                Node::native("nav").with_child(Node::text("Dashboard › Invoices › #2024-017")),
+            )
+            .with_child(invoice)
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Print invoice"))
+                    .on_click(Message::Print),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Print => {
+                self.printing = true;
+                Some(Effect::Batch(vec![
+                    Effect::Print,
+                    // Runs once the dialog is closed.
+                    Effect::Delay {
+                        ms: 0,
+                        msg: Box::new(Message::Printed),
+                    },
+                ]))
+            }
+            Message::Printed => {
+                self.printing = false;
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(Invoice::default(), "body");
+}
@@ -0,0 +1,75 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum CounterMessage {
+    Increment,
+}
+
+#[derive(Default)]
+struct Counter {
+    count: u32,
+}
+
+impl Component for Counter {
+    type Message = CounterMessage;
+
+    fn view(&self) -> Node {
+        Node::native("button")
+            .with_child(Node::text(format!("Clicked {} times", self.count)))
+            .on_click(CounterMessage::Increment)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            CounterMessage::Increment => self.count += 1,
+        }
+        None
+    }
+}
+
+#[derive(Debug)]
+enum GreetingMessage {
+    Name(String),
+}
+
+#[derive(Default)]
+struct Greeting {
+    name: String,
+}
+
+impl Component for Greeting {
+    type Message = GreetingMessage;
+
+    fn view(&self) -> Node {
+        Node::native("label")
+            .with_child(Node::text("Name "))
+            .with_child(
+                Node::native("input")
+                    .with_value(self.name.as_str())
+                    .on_input(GreetingMessage::Name),
+            )
+            .with_child(Node::text(format!(" Hello, {}!", self.name)))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            GreetingMessage::Name(name) => self.name = name,
+        }
+        None
+    }
+}
+
+fn main() {
+    // Stands in for a page rendered by something else, with two places
+    // slick should take over.
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+    let counter = document.create_element("section").unwrap();
+    let greeting = document.create_element("section").unwrap();
+    body.append_child(&counter).unwrap();
+    body.append_child(&greeting).unwrap();
+
+    // Two independent runtimes, neither knows about the other.
+    slick::mount_into(&counter, Counter::default());
+    slick::mount_into(&greeting, Greeting::default());
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use slick::{read_json, Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Hydrated(Props),
+}
+
+/// What the server hands the widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Props {
+    id: u32,
+    title: String,
+}
+
+#[derive(Default)]
+struct App {
+    props: Option<Props>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        // Rendered on the server, quotes in the JSON are escaped into valid
+        // markup by `to_html`.
+        let props = Node::native("div")
+            .with_attribute("id", "widget")
+            .with_json_of(
+                "data-props",
+                &Props {
+                    id: 5,
+                    title: "Say \"hi\"".to_string(),
+                },
+            );
+
+        Node::native("main")
+            .with_child(props)
+            .with_child(Node::text(match &self.props {
+                Some(props) => format!("Hydrated widget {}: {}", props.id, props.title),
+                None => "Not hydrated yet".to_string(),
+            }))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        let Message::Hydrated(props) = msg;
+        self.props = Some(props);
+        None
+    }
+}
+
+fn main() {
+    let dispatcher = slick::run(App::default(), "body");
+
+    let props = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id("widget"))
+        .and_then(|element| read_json::<Props>(&element, "data-props"));
+
+    if let Some(props) = props {
+        dispatcher.dispatch(Message::Hydrated(props));
+    }
+}
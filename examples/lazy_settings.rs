@@ -0,0 +1,78 @@
+use js_sys::Promise;
+use slick::{lazy, Component, Effect, Node};
+use wasm_bindgen_futures::JsFuture;
+
+/// Stands in for a panel that is expensive to set up.
+struct SettingsPanel {
+    options: Vec<String>,
+}
+
+impl Component for SettingsPanel {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        self.options
+            .iter()
+            .fold(Node::native("ul"), |list, option| {
+                list.with_child(Node::native("li").with_child(Node::text(option.as_str())))
+            })
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+async fn load_settings() -> SettingsPanel {
+    // Pretend the panel has to be downloaded first.
+    let delay = Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 1500)
+            .unwrap();
+    });
+    JsFuture::from(delay).await.unwrap();
+
+    SettingsPanel {
+        options: (1..=100).map(|i| format!("Option {}", i)).collect(),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToggleSettings,
+}
+
+#[derive(Default)]
+struct App {
+    show_settings: bool,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let page = Node::native("main").with_child(
+            Node::native("button")
+                .with_child(Node::text("Settings"))
+                .on_click(Message::ToggleSettings),
+        );
+
+        if self.show_settings {
+            page.with_child(lazy(Node::text("Loading settings..."), load_settings))
+        } else {
+            page
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::ToggleSettings => self.show_settings = !self.show_settings,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
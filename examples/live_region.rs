@@ -0,0 +1,75 @@
+use slick::{
+    aria::{live_region, Politeness},
+    Component, Effect, Node,
+};
+
+const PRODUCE: &[(&str, &str)] = &[
+    ("apple", "fruit"),
+    ("banana", "fruit"),
+    ("cherry", "fruit"),
+    ("carrot", "vegetable"),
+    ("leek", "vegetable"),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Search(&'static str),
+}
+
+#[derive(Default)]
+struct App {
+    kind: Option<&'static str>,
+}
+
+impl App {
+    fn results(&self) -> Vec<&'static str> {
+        PRODUCE
+            .iter()
+            .filter(|(_, kind)| Some(*kind) == self.kind)
+            .map(|(name, _)| *name)
+            .collect()
+    }
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let results = self
+            .results()
+            .into_iter()
+            .fold(Node::native("ul"), |list, name| {
+                list.with_child(Node::native("li").with_child(Node::text(name)))
+            });
+
+        Node::native("main")
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Search fruit"))
+                    .on_click(Message::Search("fruit")),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Search vegetables"))
+                    .on_click(Message::Search("vegetable")),
+            )
+            .with_child(results)
+            .with_child(live_region(Politeness::Polite))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Search(kind) => {
+                self.kind = Some(kind);
+                Some(Effect::Announce(format!(
+                    "{} results loaded",
+                    self.results().len()
+                )))
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,38 @@
+use slick::{use_state, Component, Effect, Node};
+
+/// A toggle that keeps whether it is on by itself, without a message type.
+fn toggle(label: &'static str) -> Node {
+    let (on, set_on) = use_state(|| false);
+
+    Node::native("button")
+        .with_attribute("aria-pressed", on)
+        .with_style("margin-right", "8px")
+        .with_style("background", if on { "#2a7" } else { "#ddd" })
+        .with_child(Node::text(format!(
+            "{}: {}",
+            label,
+            if on { "on" } else { "off" }
+        )))
+        .on_click_with(move || set_on.set(!on))
+}
+
+struct Settings;
+
+impl Component for Settings {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(Node::native("h1").with_child(Node::text("Settings")))
+            .with_child(Node::pure(toggle, "Notifications"))
+            .with_child(Node::pure(toggle, "Dark mode"))
+    }
+
+    fn update(&mut self, _: ()) -> Option<Effect> {
+        None
+    }
+}
+
+fn main() {
+    slick::run(Settings, "body");
+}
@@ -0,0 +1,56 @@
+use slick::{Component, Effect, Node};
+use wasm_bindgen::JsValue;
+
+/// Stands in for a third-party map library: it renders its own markers and
+/// reports clicks through a callback.
+const FAKE_MAP: &str = "
+    const map = document.createElement('div');
+    map.style = 'position: relative; width: 300px; height: 200px; background: #cde';
+    ['harbor', 'castle', 'market'].forEach((id, i) => {
+        const marker = document.createElement('button');
+        marker.textContent = '📍 ' + id;
+        marker.style = `position: absolute; left: ${30 + i * 80}px; top: ${40 + i * 40}px`;
+        marker.addEventListener('click', () => onMarkerClick(id));
+        map.appendChild(marker);
+    });
+    document.body.appendChild(map);
+";
+
+#[derive(Debug)]
+enum Message {
+    MarkerClicked(String),
+}
+
+#[derive(Default)]
+struct App {
+    selected: Option<String>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("p").with_child(Node::text(match &self.selected {
+            Some(marker) => format!("Selected marker: {}", marker),
+            None => "Click a marker on the map".to_string(),
+        }))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::MarkerClicked(marker) => self.selected = Some(marker),
+        }
+        None
+    }
+}
+
+fn main() {
+    let dispatcher = slick::run(App::default(), "body");
+
+    let on_marker_click =
+        dispatcher.to_js_function(|id| id.as_string().map(Message::MarkerClicked));
+
+    js_sys::Function::new_with_args("onMarkerClick", FAKE_MAP)
+        .call1(&JsValue::NULL, &on_marker_click)
+        .expect("the map failed to load");
+}
@@ -0,0 +1,61 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+#[derive(Default)]
+struct App {
+    count: i32,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(Node::native("p").with_child(Node::text_of(self.count)))
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("-"))
+                    .on_click(Message::Decrement),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("+"))
+                    .on_click(Message::Increment),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Reset"))
+                    .on_click(Message::Reset),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        // Every message shows up in the console, think of it as a trace of
+        // what the app did.
+        let log = Effect::Log(format!("{:?}", msg));
+
+        match msg {
+            Message::Increment => self.count += 1,
+            Message::Decrement if self.count == 0 => {
+                return Some(Effect::Batch(vec![
+                    log,
+                    Effect::Warn("the count can't go below zero".to_string()),
+                ]))
+            }
+            Message::Decrement => self.count -= 1,
+            Message::Reset => self.count = 0,
+        }
+
+        Some(log)
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
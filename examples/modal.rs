@@ -0,0 +1,56 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Open,
+    Close,
+}
+
+#[derive(Default)]
+struct App {
+    open: bool,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let page = Node::native("main").with_child(
+            Node::native("button")
+                .with_child(Node::text("Delete account"))
+                .on_click(Message::Open),
+        );
+
+        if !self.open {
+            return page;
+        }
+
+        page.with_child(
+            Node::native("div")
+                .with_attribute("role", "dialog")
+                .with_attribute("aria-modal", "true")
+                .with_child(Node::native("p").with_child(Node::text("Are you sure?")))
+                .with_child(Node::native("input").with_attribute("placeholder", "Type DELETE"))
+                .with_child(
+                    Node::native("button")
+                        .with_child(Node::text("Cancel"))
+                        .on_click(Message::Close),
+                )
+                .with_child(
+                    Node::native("button")
+                        .with_child(Node::text("Delete"))
+                        .on_click(Message::Close),
+                )
+                .with_focus_trap(),
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        self.open = matches!(msg, Message::Open);
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,111 @@
+//! Times building a long list by appending the rows to the live list one at
+//! a time against putting them in through one `DocumentFragment`, like the
+//! first render of slick does, and the first render itself. The results
+//! show up on the page.
+
+use slick::{Component, Effect, Node};
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element};
+
+const ROWS: usize = 10_000;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Select(usize),
+}
+
+#[derive(Default)]
+struct List {
+    selected: Option<usize>,
+}
+
+impl Component for List {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        (0..ROWS).fold(Node::native("ul"), |list, i| {
+            let row = Node::native("li")
+                .with_attribute(
+                    "class",
+                    if self.selected == Some(i) {
+                        "selected"
+                    } else {
+                        ""
+                    },
+                )
+                .with_child(Node::text(format!("Row {}", i + 1)))
+                .on_click(Message::Select(i));
+            list.with_child(row)
+        })
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        let Message::Select(i) = msg;
+        self.selected = Some(i);
+        None
+    }
+}
+
+/// Milliseconds `f` took.
+fn time(f: impl FnOnce()) -> f64 {
+    let start = js_sys::Date::now();
+    f();
+    js_sys::Date::now() - start
+}
+
+fn container(document: &Document, body: &Element) -> Element {
+    let container = document.create_element("div").unwrap();
+    body.append_child(&container).unwrap();
+    container
+}
+
+/// Builds the rows the way the list renders them into a list that is in the
+/// document, either appending each one to it or all of them at once through
+/// a fragment.
+fn append_rows(document: &Document, target: &Element, fragment: bool) {
+    let list = document.create_element("ul").unwrap();
+    target.append_child(&list).unwrap();
+    let parent: web_sys::Node = if fragment {
+        document.create_document_fragment().into()
+    } else {
+        list.clone().into()
+    };
+
+    for i in 0..ROWS {
+        let row = document.create_element("li").unwrap();
+        row.set_attribute("class", "").unwrap();
+        row.append_child(&document.create_text_node(&format!("Row {}", i + 1)))
+            .unwrap();
+        parent.append_child(&row).unwrap();
+    }
+    if fragment {
+        list.append_child(&parent).unwrap();
+    }
+}
+
+fn main() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body: Element = document.body().unwrap().unchecked_into();
+
+    let results = document.create_element("pre").unwrap();
+    body.append_child(&results).unwrap();
+
+    let mut timings = vec![];
+    for fragment in [false, true] {
+        let target = container(&document, &body);
+        timings.push(time(|| append_rows(&document, &target, fragment)));
+        target.remove();
+    }
+
+    // Clicking a row selects it, so the listeners of the mounted rows work.
+    let mounted = container(&document, &body);
+    let mount = time(|| {
+        slick::mount_into(&mounted, List::default());
+    });
+
+    results.set_text_content(Some(&format!(
+        "{} rows\nappended one by one: {} ms\nappended through a fragment: {} ms\n\
+         first render of slick: {} ms",
+        ROWS, timings[0], timings[1], mount
+    )));
+}
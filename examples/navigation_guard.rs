@@ -0,0 +1,131 @@
+use slick::{
+    router::{self, Route, Router},
+    Component, Effect, Node,
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Go(&'static str),
+    Edit(String),
+    Save,
+    Discard,
+    Stay,
+}
+
+struct App {
+    router: Router<App>,
+    draft: String,
+    saved: String,
+    /// Where the user wanted to go while the draft had unsaved changes.
+    leaving_to: Option<String>,
+}
+
+impl App {
+    fn dirty(&self) -> bool {
+        self.draft != self.saved
+    }
+}
+
+fn editor(app: &App) -> Node {
+    Node::native("div")
+        .with_child(
+            Node::native("textarea")
+                .with_attribute("rows", 8)
+                .with_attribute("cols", 60)
+                .with_value(app.draft.as_str())
+                .on_input(Message::Edit),
+        )
+        .with_child(
+            Node::native("button")
+                .with_child(Node::text("Save"))
+                .on_click(Message::Save),
+        )
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let nav = Node::native("nav")
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Editor"))
+                    .on_click(Message::Go("/")),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Help"))
+                    .on_click(Message::Go("/help")),
+            );
+
+        let mut main = Node::native("main").with_child(nav).with_child(
+            self.router
+                .view(self, &router::current_path())
+                .unwrap_or_else(|| Node::text("Not found")),
+        );
+
+        if self.leaving_to.is_some() {
+            main = main.with_child(
+                Node::native("div")
+                    .with_attribute("role", "alertdialog")
+                    .with_attribute("aria-label", "Unsaved changes")
+                    .with_focus_trap()
+                    .with_style("border", "1px solid #999")
+                    .with_style("padding", "12px")
+                    .with_child(Node::text("You have unsaved changes. Leave anyway? "))
+                    .with_child(
+                        Node::native("button")
+                            .with_child(Node::text("Discard changes"))
+                            .on_click(Message::Discard),
+                    )
+                    .with_child(
+                        Node::native("button")
+                            .with_child(Node::text("Keep editing"))
+                            .on_click(Message::Stay),
+                    ),
+            );
+        }
+
+        main
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Go(path) => return Some(Effect::Navigate(path.to_string())),
+            Message::Edit(draft) => self.draft = draft,
+            Message::Save => self.saved = self.draft.clone(),
+            Message::Discard => {
+                self.draft = self.saved.clone();
+                // Skips `before_navigate`, which would ask again otherwise.
+                return self.leaving_to.take().map(Effect::ForceNavigate);
+            }
+            Message::Stay => self.leaving_to = None,
+        }
+        None
+    }
+
+    /// Also runs for the browser's back and forward buttons.
+    fn before_navigate(&mut self, from: &str, to: &str) -> bool {
+        if from == "/" && self.dirty() {
+            self.leaving_to = Some(to.to_string());
+            return false;
+        }
+        true
+    }
+}
+
+fn main() {
+    slick::run(
+        App {
+            router: Router::new()
+                .with_route(Route::new("/", |app: &App, _| editor(app)))
+                .with_route(Route::new("/help", |_, _| {
+                    Node::text("Write something, then try leaving without saving.")
+                })),
+            draft: String::new(),
+            saved: String::new(),
+            leaving_to: None,
+        },
+        "body",
+    );
+}
@@ -0,0 +1,45 @@
+use slick::{
+    router::{Route, Router},
+    Node,
+};
+
+struct Settings {
+    username: String,
+}
+
+fn router() -> Router<Settings> {
+    Router::new().with_route(
+        Route::new("/settings", |_, m| {
+            Node::native("div")
+                .with_child(
+                    Node::native("nav")
+                        .with_child(Node::text("Profile"))
+                        .with_child(Node::text("Notifications")),
+                )
+                .with_child(
+                    m.child
+                        .unwrap_or_else(|| Node::text("Pick a section on the left")),
+                )
+        })
+        .with_child(Route::new("profile", |settings: &Settings, _| {
+            Node::native("section")
+                .with_child(Node::text(format!("Signed in as {}", settings.username)))
+        }))
+        .with_child(Route::new("notifications", |_, _| {
+            Node::native("section").with_child(Node::text("Email me about everything"))
+        })),
+    )
+}
+
+fn main() {
+    let router = router();
+    let settings = Settings {
+        username: "tim".to_string(),
+    };
+
+    for path in ["/settings", "/settings/profile", "/settings/notifications"] {
+        if let Some(view) = router.view(&settings, path) {
+            println!("{}:\n{}\n", path, view.to_html());
+        }
+    }
+}
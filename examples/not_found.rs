@@ -0,0 +1,23 @@
+use slick::{
+    router::{Route, Router},
+    Node,
+};
+
+fn main() {
+    let router: Router = Router::new()
+        .with_route(Route::new("/", |_, _| Node::text("Home")))
+        .with_route(Route::new("/about", |_, _| Node::text("About us")))
+        .with_fallback(|_, path| {
+            Node::native("div")
+                .with_child(Node::native("h1").with_child(Node::text("Page not found")))
+                .with_child(
+                    Node::native("p").with_child(Node::text(format!("Nothing lives at {}", path))),
+                )
+        });
+
+    for path in ["/about", "/does/not/exist"] {
+        if let Some(view) = router.view(&(), path) {
+            println!("{}:\n{}\n", path, view.to_html());
+        }
+    }
+}
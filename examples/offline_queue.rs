@@ -0,0 +1,111 @@
+use slick::{Component, Effect, Node, Subscription};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(String),
+    Add,
+    Connectivity(bool),
+}
+
+struct App {
+    online: bool,
+    draft: String,
+    todos: Vec<String>,
+    /// Added while offline, sent once the connection is back.
+    queued: Vec<String>,
+}
+
+impl App {
+    fn send(todo: &str) -> Effect {
+        // A real app would POST it here.
+        Effect::Log(format!("sent `{}`", todo))
+    }
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let mut main = Node::native("main");
+
+        if !self.online {
+            main = main.with_child(
+                Node::native("div")
+                    .with_attribute("role", "status")
+                    .with_style("padding", "8px 12px")
+                    .with_style("background", "#fe9")
+                    .with_child(Node::text(format!(
+                        "You're offline. {} change(s) will be sent once you're back.",
+                        self.queued.len()
+                    ))),
+            );
+        }
+
+        let todos = self.todos.iter().fold(Node::native("ul"), |list, todo| {
+            let pending = self.queued.contains(todo);
+            list.with_child(
+                Node::native("li")
+                    .with_style("opacity", if pending { "0.5" } else { "1" })
+                    .with_child(Node::text(todo.as_str())),
+            )
+        });
+
+        main.with_child(
+            Node::native("input")
+                .with_attribute("placeholder", "What needs doing?")
+                .with_value(self.draft.as_str())
+                .on_input(Message::Edit),
+        )
+        .with_child(
+            Node::native("button")
+                .with_child(Node::text("Add"))
+                .on_click(Message::Add),
+        )
+        .with_child(todos)
+    }
+
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::on_connectivity_change(Message::Connectivity)]
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(draft) => self.draft = draft,
+            Message::Add => {
+                let todo = self.draft.trim().to_string();
+                self.draft.clear();
+                if todo.is_empty() {
+                    return None;
+                }
+
+                self.todos.push(todo.clone());
+                if self.online {
+                    return Some(App::send(&todo));
+                }
+                self.queued.push(todo);
+            }
+            Message::Connectivity(online) => {
+                self.online = online;
+                if online && !self.queued.is_empty() {
+                    let queued = std::mem::take(&mut self.queued);
+                    return Some(Effect::Batch(
+                        queued.iter().map(|todo| App::send(todo)).collect(),
+                    ));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(
+        App {
+            online: slick::is_online(),
+            draft: String::new(),
+            todos: vec![],
+            queued: vec![],
+        },
+        "body",
+    );
+}
@@ -0,0 +1,96 @@
+use slick::{Component, Effect, Node};
+
+struct Article {
+    title: &'static str,
+    summary: &'static str,
+    image: &'static str,
+}
+
+const ARTICLES: [Article; 3] = [
+    Article {
+        title: "Diffing keyed children",
+        summary: "How moving an element beats rewriting every item in between.",
+        image: "https://picsum.photos/id/1/1200/630",
+    },
+    Article {
+        title: "Effects without side effects",
+        summary: "Keeping update pure by describing what should happen.",
+        image: "https://picsum.photos/id/2/1200/630",
+    },
+    Article {
+        title: "Routing on static hosts",
+        summary: "Hash routing for servers that can't rewrite paths.",
+        image: "https://picsum.photos/id/3/1200/630",
+    },
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Open(usize),
+}
+
+#[derive(Default)]
+struct App {
+    open: usize,
+}
+
+/// What link previews and the tab show for `article`.
+fn head_tags(article: &Article) -> Effect {
+    let meta = |key: &str, content: &str| Effect::SetMeta {
+        key: key.to_string(),
+        content: content.to_string(),
+    };
+
+    Effect::Batch(vec![
+        Effect::SetTitle(format!("{} | Blog", article.title)),
+        meta("description", article.summary),
+        meta("og:title", article.title),
+        meta("og:description", article.summary),
+        meta("og:image", article.image),
+    ])
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let article = &ARTICLES[self.open];
+        let list = ARTICLES
+            .iter()
+            .enumerate()
+            .fold(Node::native("ul"), |list, (i, article)| {
+                list.with_child(
+                    Node::native("li").with_child(
+                        Node::native("button")
+                            .with_child(Node::text(article.title))
+                            .on_click(Message::Open(i)),
+                    ),
+                )
+            });
+
+        Node::native("main").with_child(list).with_child(
+            Node::native("article")
+                .with_child(Node::native("h1").with_child(Node::text(article.title)))
+                .with_child(
+                    Node::native("img")
+                        .with_attribute("src", article.image)
+                        .with_attribute("alt", "")
+                        .with_attribute("width", 600),
+                )
+                .with_child(Node::native("p").with_child(Node::text(article.summary))),
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Open(i) => {
+                self.open = i;
+                Some(head_tags(&ARTICLES[i]))
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body").dispatch(Message::Open(0));
+}
@@ -0,0 +1,82 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug)]
+enum Message {
+    Edit(String),
+    Paste(String),
+    Copied,
+}
+
+#[derive(Default)]
+struct App {
+    text: String,
+    copies: u32,
+}
+
+/// Turns whatever word processors and web pages put on the clipboard into
+/// one line of plain ASCII punctuation.
+fn sanitize(pasted: &str) -> String {
+    pasted
+        .chars()
+        .filter_map(|c| match c {
+            '\u{2018}' | '\u{2019}' => Some('\''),
+            '\u{201c}' | '\u{201d}' => Some('"'),
+            '\u{2013}' | '\u{2014}' => Some('-'),
+            '\u{00a0}' => Some(' '),
+            // Zero width spaces and joiners.
+            '\u{200b}'..='\u{200d}' | '\u{feff}' => None,
+            c if c.is_whitespace() => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect::<String>()
+        .split(' ')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(
+                Node::native("label")
+                    .with_child(Node::text("Title "))
+                    .with_child(
+                        Node::native("input")
+                            .with_attribute("size", 60)
+                            .with_value(self.text.as_str())
+                            .on_input(Message::Edit)
+                            // Appended by the update instead, after cleaning it up.
+                            .on_paste_prevent_default(Message::Paste)
+                            .on_copy(|| Message::Copied),
+                    ),
+            )
+            .with_child(Node::native("p").with_child(Node::text(format!(
+                "{} characters, copied {} times",
+                self.text.chars().count(),
+                self.copies
+            ))))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(text) => self.text = text,
+            Message::Paste(pasted) => {
+                let pasted = sanitize(&pasted);
+                if !self.text.is_empty() && !self.text.ends_with(' ') && !pasted.is_empty() {
+                    self.text.push(' ');
+                }
+                self.text.push_str(&pasted);
+            }
+            Message::Copied => self.copies += 1,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
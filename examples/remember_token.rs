@@ -0,0 +1,104 @@
+use slick::{Component, Effect, Node};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
+
+#[derive(Debug)]
+enum Message {
+    SignIn,
+    Stored(Result<(), String>),
+}
+
+/// What the fetch resolves to. It is its own type, so `Effect::then` can
+/// tell it apart from the messages `update` gets.
+#[derive(Debug)]
+struct Token(Result<String, String>);
+
+#[derive(Default)]
+struct SignIn {
+    pending: bool,
+    result: Option<Result<(), String>>,
+}
+
+async fn fetch_token() -> Token {
+    let request = JsFuture::from(web_sys::window().unwrap().fetch_with_str("/api/token"));
+
+    let token = async {
+        let response: Response = request
+            .await
+            .map_err(|_| "the request failed".to_string())?
+            .unchecked_into();
+
+        if !response.ok() {
+            return Err(format!("the server responded with {}", response.status()));
+        }
+
+        let body = response.text().map_err(|_| "the body can't be read")?;
+        JsFuture::from(body)
+            .await
+            .ok()
+            .and_then(|body| body.as_string())
+            .ok_or_else(|| "the body isn't text".to_string())
+    };
+    Token(token.await)
+}
+
+fn store(token: &str) -> Result<(), String> {
+    web_sys::window()
+        .unwrap()
+        .local_storage()
+        .ok()
+        .flatten()
+        .ok_or("local storage is unavailable")?
+        .set_item("token", token)
+        .map_err(|_| "the storage is full".to_string())
+}
+
+impl Component for SignIn {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let status = match (&self.result, self.pending) {
+            (_, true) => "Signing in…".to_string(),
+            (None, false) => "Not signed in".to_string(),
+            (Some(Ok(())), false) => "Signed in, the token is remembered".to_string(),
+            (Some(Err(error)), false) => format!("Couldn't sign in: {}", error),
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("button")
+                    .disabled(self.pending)
+                    .with_child(Node::text("Sign in"))
+                    .on_click_with(|| Message::SignIn),
+            )
+            .with_child(Node::native("p").with_child(Node::text(status)))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::SignIn => {
+                self.pending = true;
+                // The token is only stored once the fetch resolved, a
+                // `Batch` would start both at the same time.
+                Some(Effect::then(
+                    Effect::command(fetch_token()),
+                    |Token(token)| {
+                        Effect::command(async move {
+                            Message::Stored(token.and_then(|token| store(&token)))
+                        })
+                    },
+                ))
+            }
+            Message::Stored(result) => {
+                self.pending = false;
+                self.result = Some(result);
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(SignIn::default(), "body");
+}
@@ -0,0 +1,80 @@
+use slick::{css::px, Component, Effect, Node};
+
+const VALUES: [u32; 12] = [3, 7, 4, 9, 12, 8, 5, 10, 14, 11, 6, 9];
+const HEIGHT: f64 = 200.0;
+
+#[derive(Debug)]
+enum Message {
+    Resized(f64),
+}
+
+#[derive(Default)]
+struct App {
+    width: Option<f64>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let container = Node::native("div")
+            .with_attribute(
+                "style",
+                "resize: horizontal; overflow: hidden; width: 60%; min-width: 120px; \
+                 border: 1px solid #ccc",
+            )
+            .on_resize(|width, _| Message::Resized(width));
+
+        // Until the first size comes in there is nothing to fit the bars to.
+        let width = match self.width {
+            Some(width) => width,
+            None => return Node::native("main").with_child(container),
+        };
+
+        let max = VALUES.iter().copied().max().unwrap_or(1) as f64;
+        let bar_width = width / VALUES.len() as f64;
+        // Labels only fit if the bars are wide enough.
+        let labels = bar_width >= 24.0;
+
+        let chart = VALUES.iter().fold(
+            Node::native("div")
+                .with_style("display", "flex")
+                .with_style("align-items", "flex-end")
+                .with_style("height", px(HEIGHT)),
+            |chart, &value| {
+                let bar = Node::native("div")
+                    .with_style("width", px(bar_width))
+                    .with_style("height", px(value as f64 / max * HEIGHT))
+                    .with_style("background", "steelblue")
+                    .with_style("color", "white")
+                    .with_style("text-align", "center")
+                    .with_style("box-sizing", "border-box")
+                    .with_style("border-right", "1px solid white");
+
+                chart.with_child(if labels {
+                    bar.with_child(Node::text_of(value))
+                } else {
+                    bar
+                })
+            },
+        );
+
+        Node::native("main")
+            .with_child(Node::native("p").with_child(Node::text(format!(
+                "Drag the corner to resize, the chart is {:.0}px wide",
+                width
+            ))))
+            .with_child(container.with_child(chart))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Resized(width) => self.width = Some(width),
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
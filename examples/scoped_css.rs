@@ -0,0 +1,79 @@
+use slick::{Component, Effect, Node};
+
+struct Alert;
+
+slick::scoped_classes! {
+    struct AlertStyles for Alert { panel }
+}
+
+impl Component for Alert {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        let styles = AlertStyles::new();
+
+        Node::native("div")
+            .with_child(Node::native("style").with_child(Node::text(
+                styles.css(".panel { border: 2px solid crimson; color: crimson; padding: 8px }"),
+            )))
+            .with_child(
+                Node::native("div")
+                    .with_attribute("class", styles.panel.as_str())
+                    .with_child(Node::text("Something went wrong")),
+            )
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+struct Card;
+
+slick::scoped_classes! {
+    struct CardStyles for Card { panel }
+}
+
+impl Component for Card {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        let styles = CardStyles::new();
+
+        Node::native("div")
+            .with_child(Node::native("style").with_child(Node::text(
+                styles.css(
+                    ".panel { box-shadow: 0 2px 6px #0003; border-radius: 4px; padding: 16px }",
+                ),
+            )))
+            .with_child(
+                Node::native("div")
+                    .with_attribute("class", styles.panel.as_str())
+                    .with_child(Node::text("Both components use a `panel` class")),
+            )
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+struct App;
+
+impl Component for App {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(Node::custom(Box::new(Alert)))
+            .with_child(Node::custom(Box::new(Card)))
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+fn main() {
+    slick::run(App, "body");
+}
@@ -0,0 +1,58 @@
+use slick::{
+    router::{self, Route, Router},
+    Component, Effect, Node,
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Open(u32),
+}
+
+struct App {
+    router: Router<App>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self {
+            router: Router::new()
+                .with_route(Route::new("/", |_, _| {
+                    (1..=200).fold(Node::native("ul"), |list, i| {
+                        list.with_child(
+                            Node::native("li")
+                                .with_child(Node::text(format!("Item {}", i)))
+                                .on_click(Message::Open(i)),
+                        )
+                    })
+                }))
+                .with_route(Route::new("/items/:id", |_, m| {
+                    Node::native("p").with_child(Node::text(format!(
+                        "This is item {}. Go back to land where you left the list.",
+                        m.params["id"]
+                    )))
+                })),
+        }
+    }
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        self.router
+            .view(self, &router::current_path())
+            .unwrap_or_else(|| Node::text("Not found"))
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Open(id) => Some(Effect::Navigate(format!("/items/{}", id))),
+        }
+    }
+}
+
+fn main() {
+    // Call `router::set_scroll_restoration(false)` to keep the browser's
+    // default behavior instead.
+    slick::run(App::default(), "body");
+}
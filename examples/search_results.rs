@@ -0,0 +1,141 @@
+use slick::{
+    router::{self, Match, Route, Router},
+    Component, Effect, Modifiers, Node,
+};
+
+const PER_PAGE: usize = 3;
+const CRATES: [&str; 8] = [
+    "serde",
+    "serde_json",
+    "rustls",
+    "rust-embed",
+    "tokio",
+    "trust-dns",
+    "rustyline",
+    "wasm-bindgen",
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(String),
+    Search,
+    Page(String, usize),
+}
+
+struct App {
+    router: Router<App>,
+    draft: String,
+}
+
+/// The link for page `page` of the results for `q`.
+fn search_path(q: &str, page: usize) -> String {
+    let q: String = q
+        .bytes()
+        .map(|byte| match byte {
+            b' ' => "+".to_string(),
+            byte if byte.is_ascii_alphanumeric() || b"-_.".contains(&byte) => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect();
+    format!("/search?q={}&page={}", q, page)
+}
+
+fn results(m: Match) -> Node {
+    let q = m.query.get("q").map(String::as_str).unwrap_or_default();
+    let page = m
+        .query
+        .get("page")
+        .and_then(|page| page.parse().ok())
+        .unwrap_or(1usize)
+        .max(1);
+
+    let matches: Vec<&str> = CRATES
+        .iter()
+        .copied()
+        .filter(|name| name.contains(q))
+        .collect();
+    let pages = matches.len().div_ceil(PER_PAGE).max(1);
+
+    let list = matches
+        .iter()
+        .skip((page - 1) * PER_PAGE)
+        .take(PER_PAGE)
+        .fold(Node::native("ul"), |list, name| {
+            list.with_child(Node::native("li").with_child(Node::text(*name)))
+        });
+
+    let mut pager = Node::native("p").with_child(Node::text(format!(
+        "{} results for \"{}\", page {} of {} ",
+        matches.len(),
+        q,
+        page,
+        pages
+    )));
+    if page > 1 {
+        pager = pager.with_child(
+            Node::native("button")
+                .with_child(Node::text("Previous"))
+                .on_click(Message::Page(q.to_string(), page - 1)),
+        );
+    }
+    if page < pages {
+        pager = pager.with_child(
+            Node::native("button")
+                .with_child(Node::text("Next"))
+                .on_click(Message::Page(q.to_string(), page + 1)),
+        );
+    }
+
+    Node::native("div").with_child(list).with_child(pager)
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let path = router::current_path();
+
+        Node::native("main")
+            .with_child(
+                Node::native("input")
+                    .with_attribute("type", "search")
+                    .with_attribute("placeholder", "Search crates")
+                    .with_value(self.draft.as_str())
+                    .on_input(Message::Edit)
+                    .on_key("Enter", Modifiers::NONE, || Message::Search),
+            )
+            .with_child(
+                self.router
+                    .view(self, &path)
+                    .unwrap_or_else(|| Node::text("Not found")),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(draft) => {
+                self.draft = draft;
+                None
+            }
+            Message::Search => Some(Effect::Navigate(search_path(&self.draft, 1))),
+            Message::Page(q, page) => Some(Effect::Navigate(search_path(&q, page))),
+        }
+    }
+}
+
+fn main() {
+    slick::run(
+        App {
+            router: Router::new()
+                .with_route(Route::new("/", |_, m| results(m)))
+                .with_route(Route::new("/search", |_, m| results(m))),
+            // Reloading shows the same search.
+            draft: router::parse_query(&router::current_path())
+                .remove("q")
+                .unwrap_or_default(),
+        },
+        "body",
+    );
+}
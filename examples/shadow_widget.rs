@@ -0,0 +1,59 @@
+use slick::{Component, Effect, Node};
+
+const STYLE: &str = "
+button { background: hotpink; color: white; border: none; padding: 8px 16px; border-radius: 16px; }
+";
+
+/// Renders into a shadow root, its `button` rule only styles its own button.
+#[derive(Default)]
+struct Widget {
+    likes: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Like,
+}
+
+impl Component for Widget {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("div")
+            .with_child(Node::native("style").with_child(Node::text(STYLE)))
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text(format!("♥ {}", self.likes)))
+                    .on_click(Message::Like),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Like => self.likes += 1,
+        }
+        None
+    }
+}
+
+/// A regular page around the widget, its button keeps the default look.
+struct Page;
+
+impl Component for Page {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(Node::native("button").with_child(Node::text("A page button")))
+            .with_child(Node::native("div").with_attribute("id", "widget"))
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+fn main() {
+    slick::run(Page, "body");
+    slick::run_in_shadow(Widget::default(), "#widget");
+}
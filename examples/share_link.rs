@@ -0,0 +1,62 @@
+use slick::{Component, Effect, EffectError, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Copy,
+    Copied(Result<(), EffectError>),
+}
+
+#[derive(Default)]
+struct App {
+    status: Option<String>,
+}
+
+fn share_link() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().href().ok())
+        .unwrap_or_default()
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let mut main = Node::native("main")
+            .with_child(
+                Node::native("input")
+                    .with_attribute("readonly", "")
+                    .with_attribute("value", share_link()),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Copy share link"))
+                    .on_click(Message::Copy),
+            );
+
+        if let Some(status) = &self.status {
+            main = main.with_child(Node::native("p").with_child(Node::text(status)));
+        }
+
+        main
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Copy => {
+                return Some(Effect::CopyToClipboard(
+                    share_link(),
+                    Box::new(|result| Box::new(Message::Copied(result))),
+                ))
+            }
+            Message::Copied(Ok(())) => self.status = Some("Link copied".to_string()),
+            Message::Copied(Err(reason)) => {
+                self.status = Some(format!("Couldn't copy the link: {}", reason))
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,94 @@
+use slick::{Component, Effect, FieldEvent, FormState, Node};
+
+#[derive(Debug)]
+enum Message {
+    Field(FieldEvent),
+    Submit,
+}
+
+#[derive(Default)]
+struct Signup {
+    form: FormState,
+    submitted: bool,
+}
+
+impl Signup {
+    fn validate(&mut self) {
+        self.form.validate("email", |email| {
+            if email.is_empty() {
+                Some("Enter your email address".to_string())
+            } else if !email.contains('@') {
+                Some("This doesn't look like an email address".to_string())
+            } else {
+                None
+            }
+        });
+        self.form.validate("password", |password| {
+            (password.chars().count() < 8).then(|| "Use at least 8 characters".to_string())
+        });
+    }
+
+    fn field(&self, name: &str, label: &str, type_: &str) -> Node {
+        Node::native("div")
+            .with_child(
+                Node::native("label")
+                    .with_attribute("for", name.to_string())
+                    .with_child(Node::text(label)),
+            )
+            .with_child(
+                self.form.bind(
+                    name,
+                    Node::native("input")
+                        .id(name.to_string())
+                        .type_(type_.to_string()),
+                    Message::Field,
+                ),
+            )
+            // Empty until the field was left once with something wrong.
+            .with_child(self.form.error_text(name))
+    }
+}
+
+impl Component for Signup {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        if self.submitted {
+            return Node::native("p").with_child(Node::text(format!(
+                "Welcome, {}!",
+                self.form.value("email")
+            )));
+        }
+
+        Node::native("div")
+            .with_child(self.field("email", "Email", "email"))
+            .with_child(self.field("password", "Password", "password"))
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Sign up"))
+                    .on_click_with(|| Message::Submit),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Field(event) => {
+                self.form.apply(event);
+                self.validate();
+            }
+            Message::Submit => {
+                // Shows the errors of fields nobody has been to yet.
+                self.form.touch_all();
+                self.submitted = self.form.is_valid();
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    let mut signup = Signup::default();
+    // Validated up front, so submitting right away reports both fields.
+    signup.validate();
+    slick::run(signup, "body");
+}
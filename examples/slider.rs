@@ -0,0 +1,88 @@
+use slick::{css::px, drag::MouseDrag, Component, Effect, Node};
+
+const TRACK_WIDTH: i32 = 300;
+const HANDLE_WIDTH: i32 = 20;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Drag(MouseDrag),
+}
+
+struct Slider {
+    /// Between 0 and 100.
+    value: f64,
+    /// The pointer position and value where the current drag started.
+    drag: Option<(i32, f64)>,
+}
+
+impl Component for Slider {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let range = f64::from(TRACK_WIDTH - HANDLE_WIDTH);
+        let handle = Node::native("div")
+            .with_attribute("role", "slider")
+            .with_attribute("aria-valuemin", 0)
+            .with_attribute("aria-valuemax", 100)
+            .with_attribute("aria-valuenow", self.value.round() as i32)
+            .with_style("position", "absolute")
+            .with_style("top", "0")
+            .with_style("left", px(self.value / 100.0 * range))
+            .with_style("width", px(HANDLE_WIDTH))
+            .with_style("height", "100%")
+            .with_style("border-radius", "4px")
+            .with_style(
+                "cursor",
+                if self.drag.is_some() {
+                    "grabbing"
+                } else {
+                    "grab"
+                },
+            )
+            .with_style(
+                "background",
+                if self.drag.is_some() { "#246" } else { "#48c" },
+            )
+            .on_mouse_drag(Message::Drag);
+
+        Node::native("main")
+            .with_child(
+                Node::native("div")
+                    .with_style("position", "relative")
+                    .with_style("width", px(TRACK_WIDTH))
+                    .with_style("height", "24px")
+                    .with_style("background", "#ddd")
+                    .with_style("border-radius", "4px")
+                    .with_child(handle),
+            )
+            .with_child(
+                Node::native("p").with_child(Node::text(format!("Volume: {:.0}", self.value))),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Drag(MouseDrag::Start((x, _))) => self.drag = Some((x, self.value)),
+            // Keeps following the pointer when it leaves the handle, or even
+            // the track.
+            Message::Drag(MouseDrag::Move((x, _))) => {
+                if let Some((start_x, start_value)) = self.drag {
+                    let moved = f64::from(x - start_x) / f64::from(TRACK_WIDTH - HANDLE_WIDTH);
+                    self.value = (start_value + moved * 100.0).clamp(0.0, 100.0);
+                }
+            }
+            Message::Drag(MouseDrag::End(_)) => self.drag = None,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(
+        Slider {
+            value: 50.0,
+            drag: None,
+        },
+        "body",
+    );
+}
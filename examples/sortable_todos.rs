@@ -0,0 +1,62 @@
+use slick::{drag::reorder, Component, Effect, Node};
+
+#[derive(Debug)]
+enum Message {
+    DragOver(usize),
+    Drop(usize, usize),
+}
+
+struct Todos {
+    items: Vec<String>,
+    over: Option<usize>,
+}
+
+impl Component for Todos {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        // Keyed, so dropping moves the dragged element instead of rewriting
+        // the text of every item in between.
+        Node::native("ul").with_children_keyed(
+            self.items.iter().enumerate(),
+            |(_, item)| item.as_str(),
+            |(i, item)| {
+                let li = Node::native("li")
+                    .with_child(Node::text(item.as_str()))
+                    .on_drag_start(i, Message::DragOver)
+                    .on_drag_over(i, |_, to| Message::DragOver(to))
+                    .on_drop(i, Message::Drop);
+
+                if self.over == Some(i) {
+                    li.with_attribute("class", "drop-target")
+                } else {
+                    li
+                }
+            },
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::DragOver(i) => self.over = Some(i),
+            Message::Drop(from, to) => {
+                reorder(&mut self.items, from, to);
+                self.over = None;
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(
+        Todos {
+            items: ["Buy milk", "Walk the dog", "Write docs", "Ship it"]
+                .iter()
+                .map(|item| item.to_string())
+                .collect(),
+            over: None,
+        },
+        "body",
+    );
+}
@@ -0,0 +1,101 @@
+use slick::{Component, Effect, Node};
+
+const COLUMNS: usize = 4;
+const ROWS: usize = 5;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Select(usize, usize),
+    Edit(usize, usize),
+    Input(String),
+    Commit,
+}
+
+struct Sheet {
+    cells: Vec<Vec<String>>,
+    selected: Option<(usize, usize)>,
+    /// The cell being edited and its draft.
+    editing: Option<((usize, usize), String)>,
+}
+
+impl Sheet {
+    fn cell(&self, row: usize, column: usize) -> Node {
+        let td = Node::native("td").with_style("border", "1px solid #ccc");
+
+        if let Some(((r, c), draft)) = &self.editing {
+            if (*r, *c) == (row, column) {
+                return td.with_child(
+                    Node::native("input")
+                        .with_value(draft.as_str())
+                        .on_input(Message::Input),
+                );
+            }
+        }
+
+        let td = td
+            .with_style("min-width", "80px")
+            .with_child(Node::text(self.cells[row][column].as_str()))
+            .on_click(Message::Select(row, column))
+            .on_double_click(move || Message::Edit(row, column));
+
+        if self.selected == Some((row, column)) {
+            td.with_style("outline", "2px solid steelblue")
+        } else {
+            td
+        }
+    }
+}
+
+impl Component for Sheet {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let table = (0..ROWS).fold(Node::native("table"), |table, row| {
+            table.with_child((0..COLUMNS).fold(Node::native("tr"), |tr, column| {
+                tr.with_child(self.cell(row, column))
+            }))
+        });
+
+        Node::native("main")
+            .with_child(Node::native("p").with_child(Node::text(
+                "Double-click a cell to edit it, click Save to keep the change",
+            )))
+            .with_child(table)
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Save"))
+                    .on_click(Message::Commit),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Select(row, column) => self.selected = Some((row, column)),
+            Message::Edit(row, column) => {
+                self.editing = Some(((row, column), self.cells[row][column].clone()))
+            }
+            Message::Input(value) => {
+                if let Some((_, draft)) = &mut self.editing {
+                    *draft = value;
+                }
+            }
+            Message::Commit => {
+                if let Some(((row, column), draft)) = self.editing.take() {
+                    self.cells[row][column] = draft;
+                }
+            }
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(
+        Sheet {
+            cells: vec![vec![String::new(); COLUMNS]; ROWS],
+            selected: None,
+            editing: None,
+        },
+        "body",
+    );
+}
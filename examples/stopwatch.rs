@@ -0,0 +1,65 @@
+use slick::{Component, Effect, Node, Subscription};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+    Reset,
+    Tick,
+}
+
+#[derive(Default)]
+struct Stopwatch {
+    running: bool,
+    tenths: u32,
+}
+
+impl Component for Stopwatch {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        Node::native("main")
+            .with_child(
+                Node::native("p")
+                    .with_style("font-size", "48px")
+                    .with_style("font-variant-numeric", "tabular-nums")
+                    .with_child(Node::text(format!(
+                        "{}.{}",
+                        self.tenths / 10,
+                        self.tenths % 10
+                    ))),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text(if self.running { "Stop" } else { "Start" }))
+                    .on_click(Message::Toggle),
+            )
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Reset"))
+                    .on_click(Message::Reset),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Toggle => self.running = !self.running,
+            Message::Reset => self.tenths = 0,
+            Message::Tick => self.tenths += 1,
+        }
+        None
+    }
+
+    // The timer only runs while the stopwatch does, stopping it is up to
+    // the runtime once it is no longer returned.
+    fn subscriptions(&self) -> Vec<Subscription> {
+        if self.running {
+            vec![Subscription::every(100, || Message::Tick)]
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn main() {
+    slick::run(Stopwatch::default(), "body");
+}
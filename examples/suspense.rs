@@ -0,0 +1,79 @@
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::Promise;
+use slick::{Component, Effect, Node, Suspense};
+use wasm_bindgen_futures::JsFuture;
+
+type Users = Rc<RefCell<Option<Vec<String>>>>;
+
+struct UserList {
+    users: Users,
+}
+
+impl Component for UserList {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        self.users
+            .borrow()
+            .iter()
+            .flatten()
+            .fold(Node::native("ul"), |list, user| {
+                list.with_child(Node::native("li").with_child(Node::text(user.as_str())))
+            })
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+
+    fn ready(&self) -> bool {
+        self.users.borrow().is_some()
+    }
+}
+
+struct App {
+    users: Users,
+}
+
+impl Component for App {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        let spinner = Node::native("div")
+            .with_attribute("class", "spinner")
+            .with_attribute("aria-busy", "true")
+            .with_child(Node::text("Loading users..."));
+
+        Node::native("main")
+            .with_child(Node::native("h1").with_child(Node::text("Users")))
+            .with_child(Suspense::new(spinner).view(Node::custom(Box::new(UserList {
+                users: self.users.clone(),
+            }))))
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> Option<Effect> {
+        None
+    }
+}
+
+async fn fetch_users(users: Users) {
+    // Stands in for a request to the backend.
+    let response = Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 2000)
+            .unwrap();
+    });
+    JsFuture::from(response).await.unwrap();
+
+    *users.borrow_mut() = Some(vec!["Ada".to_string(), "Grace".to_string()]);
+    slick::request_render();
+}
+
+fn main() {
+    let users = Users::default();
+
+    wasm_bindgen_futures::spawn_local(fetch_users(users.clone()));
+    slick::run(App { users }, "body");
+}
@@ -0,0 +1,68 @@
+use slick::{router::Location, Component, Effect, Node};
+
+const TABS: [(&str, &str); 3] = [
+    ("overview", "Everything at a glance."),
+    ("activity", "What happened lately."),
+    ("settings", "How it all behaves."),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Start,
+    Restore(Location),
+    Select(usize),
+}
+
+#[derive(Default)]
+struct App {
+    selected: usize,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let tabs = TABS.iter().enumerate().fold(
+            Node::native("div").with_attribute("role", "tablist"),
+            |tabs, (i, (name, _))| {
+                tabs.with_child(
+                    Node::native("button")
+                        .with_attribute("role", "tab")
+                        .with_attribute("aria-selected", i == self.selected)
+                        .with_child(Node::text(*name))
+                        .on_click(Message::Select(i)),
+                )
+            },
+        );
+
+        Node::native("main").with_child(tabs).with_child(
+            Node::native("p")
+                .with_attribute("role", "tabpanel")
+                .with_child(Node::text(TABS[self.selected].1)),
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Start => Some(Effect::GetLocation {
+                on_value: Box::new(|location| Box::new(Message::Restore(location))),
+            }),
+            Message::Restore(location) => {
+                let tab = location.query.get("tab").map(String::as_str);
+                self.selected = TABS
+                    .iter()
+                    .position(|(name, _)| Some(*name) == tab)
+                    .unwrap_or_default();
+                None
+            }
+            Message::Select(i) => {
+                self.selected = i;
+                Some(Effect::ReplaceUrl(format!("/?tab={}", TABS[i].0)))
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body").dispatch(Message::Start);
+}
@@ -0,0 +1,75 @@
+use slick::{Component, Effect, Node};
+
+const MEMBERS: [(&str, &str); 3] = [
+    ("Ada", "https://i.pravatar.cc/64?img=1"),
+    ("Grace", "https://i.pravatar.cc/64?img=5"),
+    ("Linus", "https://i.pravatar.cc/64?img=12"),
+];
+
+/// Nothing to update, so a function is enough.
+fn avatar(url: &str) -> Node {
+    Node::native("img")
+        .with_attribute("src", url)
+        .with_attribute("alt", "")
+        .with_attribute("width", 32)
+        .with_attribute("height", 32)
+        .with_style("border-radius", "50%")
+        .with_style("vertical-align", "middle")
+}
+
+fn member((name, url): (&'static str, &'static str)) -> Node {
+    Node::native("li")
+        .with_child(Node::pure(avatar, url))
+        .with_child(Node::text(format!(" {}", name)))
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToggleCompact,
+}
+
+#[derive(Default)]
+struct App {
+    compact: bool,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let members = if self.compact {
+            MEMBERS
+                .iter()
+                .fold(Node::native("p"), |members, &(_, url)| {
+                    members.with_child(Node::pure(avatar, url))
+                })
+        } else {
+            MEMBERS.iter().fold(Node::native("ul"), |members, &props| {
+                members.with_child(Node::pure(member, props))
+            })
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text(if self.compact {
+                        "Show names"
+                    } else {
+                        "Hide names"
+                    }))
+                    .on_click(Message::ToggleCompact),
+            )
+            .with_child(members)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::ToggleCompact => self.compact = !self.compact,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
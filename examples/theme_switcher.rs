@@ -0,0 +1,68 @@
+use slick::{Component, Effect, Node};
+
+const ACCENTS: [(&str, &str); 3] = [
+    ("Blue", "#0366d6"),
+    ("Green", "#22863a"),
+    ("Purple", "#6f42c1"),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Pick(Option<&'static str>),
+}
+
+#[derive(Default)]
+struct App {
+    accent: Option<&'static str>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let buttons = ACCENTS
+            .iter()
+            .fold(Node::native("p"), |row, &(name, color)| {
+                row.with_child(
+                    Node::native("button")
+                        .with_attribute("aria-pressed", self.accent == Some(color))
+                        .with_style("margin-right", "8px")
+                        .with_child(Node::text(name))
+                        .on_click(Message::Pick(Some(color))),
+                )
+            });
+
+        Node::native("main")
+            .with_child(
+                Node::native("h1")
+                    .with_style("color", "var(--accent, #333)")
+                    .with_child(Node::text("Theme switcher")),
+            )
+            .with_child(
+                buttons.with_child(
+                    Node::native("button")
+                        .with_child(Node::text("Default"))
+                        .on_click(Message::Pick(None)),
+                ),
+            )
+            .with_child(
+                Node::native("div")
+                    .with_style("height", "8px")
+                    .with_style("background", "var(--accent, #333)"),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        let Message::Pick(accent) = msg;
+        self.accent = accent;
+
+        Some(Effect::SetCssVar {
+            name: "--accent".to_string(),
+            value: accent.map(str::to_string),
+        })
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
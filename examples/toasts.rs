@@ -0,0 +1,90 @@
+use slick::{Component, Effect, Node};
+
+const STYLE: &str = "
+.toast { animation: enter 200ms ease-out; padding: 8px; margin: 4px; background: #333; color: white; }
+.toast.leaving { animation: leave 300ms ease-in forwards; }
+@keyframes enter { from { opacity: 0; transform: translateY(8px); } }
+@keyframes leave { to { opacity: 0; transform: translateX(100%); } }
+";
+
+#[derive(Debug, Clone)]
+enum Message {
+    Show,
+    Dismiss(usize),
+    AnimationEnd(usize, String),
+}
+
+struct Toast {
+    id: usize,
+    text: String,
+    leaving: bool,
+}
+
+#[derive(Default)]
+struct App {
+    next_id: usize,
+    toasts: Vec<Toast>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let toasts = self.toasts.iter().fold(Node::native("div"), |list, toast| {
+            let id = toast.id;
+            list.with_child(
+                Node::native("div")
+                    .with_attribute(
+                        "class",
+                        if toast.leaving {
+                            "toast leaving"
+                        } else {
+                            "toast"
+                        },
+                    )
+                    .with_child(Node::text(&toast.text))
+                    .on_click(Message::Dismiss(id))
+                    .on_animation_end(move |name| Message::AnimationEnd(id, name)),
+            )
+        });
+
+        Node::native("main")
+            .with_child(Node::native("style").with_child(Node::text(STYLE)))
+            .with_child(
+                Node::native("button")
+                    .with_child(Node::text("Show toast"))
+                    .on_click(Message::Show),
+            )
+            .with_child(Node::native("p").with_child(Node::text("Click a toast to dismiss it")))
+            .with_child(toasts)
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Show => {
+                self.next_id += 1;
+                self.toasts.push(Toast {
+                    id: self.next_id,
+                    text: format!("Toast #{}", self.next_id),
+                    leaving: false,
+                });
+            }
+            Message::Dismiss(id) => {
+                if let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id) {
+                    toast.leaving = true;
+                }
+            }
+            // The enter animation ends too, only the exit one removes the
+            // toast.
+            Message::AnimationEnd(id, name) if name == "leave" => {
+                self.toasts.retain(|toast| toast.id != id)
+            }
+            Message::AnimationEnd(..) => {}
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
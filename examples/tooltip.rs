@@ -0,0 +1,94 @@
+use slick::{css::px, Component, Effect, Node, Rect};
+
+const TERMS: [(&str, &str); 3] = [
+    (
+        "wasm",
+        "WebAssembly, a binary format browsers run next to JavaScript.",
+    ),
+    (
+        "vdom",
+        "A tree describing the page, compared to the previous one to patch the DOM.",
+    ),
+    (
+        "effect",
+        "Something update asks the runtime to do, like a request or a delay.",
+    ),
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle(usize),
+    Place(usize, Rect),
+}
+
+#[derive(Default)]
+struct App {
+    open: Option<usize>,
+    position: Option<Rect>,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let terms = TERMS.iter().enumerate().map(|(i, (term, _))| {
+            Node::native("button")
+                .with_attribute("id", format!("term-{}", i))
+                .with_attribute("aria-describedby", "tooltip")
+                .with_style("margin", "0 48px 0 0")
+                .with_child(Node::text(*term))
+                .on_click(Message::Toggle(i))
+        });
+
+        let mut main = terms.fold(
+            Node::native("main").with_style("padding", "64px"),
+            Node::with_child,
+        );
+
+        if let (Some(open), Some(rect)) = (self.open, self.position) {
+            main = main.with_child(
+                Node::native("div")
+                    .with_attribute("id", "tooltip")
+                    .with_attribute("role", "tooltip")
+                    .with_style("position", "fixed")
+                    .with_style("left", px(rect.x as i32))
+                    .with_style("top", px((rect.y + rect.height) as i32 + 6))
+                    .with_style("max-width", "240px")
+                    .with_style("padding", "6px 10px")
+                    .with_style("background", "#333")
+                    .with_style("color", "white")
+                    .with_style("border-radius", "4px")
+                    .with_child(Node::text(TERMS[open].1)),
+            );
+        }
+
+        main
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Toggle(i) if self.open == Some(i) => {
+                self.open = None;
+                None
+            }
+            Message::Toggle(i) => {
+                self.open = Some(i);
+                self.position = None;
+                Some(Effect::Measure {
+                    selector: format!("#term-{}", i),
+                    on_rect: Box::new(move |rect| Box::new(Message::Place(i, rect))),
+                })
+            }
+            Message::Place(i, rect) => {
+                if self.open == Some(i) {
+                    self.position = Some(rect);
+                }
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
@@ -0,0 +1,59 @@
+use slick::{Component, Effect, Node};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(String),
+    Save,
+}
+
+#[derive(Default)]
+struct Editor {
+    text: String,
+    saved: String,
+}
+
+impl Component for Editor {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        let status = if self.unsaved_changes() {
+            "Unsaved changes, reloading or closing the tab asks first"
+        } else {
+            "All changes saved"
+        };
+
+        Node::native("main")
+            .with_child(
+                Node::native("textarea")
+                    .with_attribute("rows", 10)
+                    .with_attribute("cols", 60)
+                    .with_value(self.text.as_str())
+                    .on_input(Message::Edit),
+            )
+            .with_child(
+                Node::native("p")
+                    .with_child(
+                        Node::native("button")
+                            .with_child(Node::text("Save"))
+                            .on_click(Message::Save),
+                    )
+                    .with_child(Node::text(format!(" {}", status))),
+            )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Edit(text) => self.text = text,
+            Message::Save => self.saved = self.text.clone(),
+        }
+        None
+    }
+
+    fn unsaved_changes(&self) -> bool {
+        self.text != self.saved
+    }
+}
+
+fn main() {
+    slick::run(Editor::default(), "body");
+}
@@ -0,0 +1,37 @@
+use slick::{virtual_list, Component, Effect, Node};
+
+#[derive(Debug)]
+enum Message {
+    Scrolled(i32),
+}
+
+#[derive(Default)]
+struct App {
+    scroll_top: i32,
+}
+
+impl Component for App {
+    type Message = Message;
+
+    fn view(&self) -> Node {
+        virtual_list(
+            50_000,
+            24,
+            600,
+            self.scroll_top,
+            |i| Node::text(format!("Row {}", i + 1)),
+            Message::Scrolled,
+        )
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Effect> {
+        match msg {
+            Message::Scrolled(top) => self.scroll_top = top,
+        }
+        None
+    }
+}
+
+fn main() {
+    slick::run(App::default(), "body");
+}
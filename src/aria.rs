@@ -0,0 +1,65 @@
+use web_sys::Element;
+
+use crate::Node;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Politeness {
+    /// Announced once the screen reader is idle.
+    Polite,
+    /// Announced immediately, interrupting the current speech.
+    Assertive,
+}
+
+impl Politeness {
+    fn as_str(self) -> &'static str {
+        match self {
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        }
+    }
+}
+
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; margin: -1px; \
+    padding: 0; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0";
+
+/// A visually hidden region that [`Effect::Announce`](crate::Effect::Announce)
+/// and [`Effect::AnnounceAssertive`](crate::Effect::AnnounceAssertive) write
+/// into. Render it once per priority you use; it has to be in the document
+/// before the first announcement for screen readers to pick it up.
+pub fn live_region(politeness: Politeness) -> Node {
+    Node::native("div")
+        .with_attribute(
+            "role",
+            match politeness {
+                Politeness::Polite => "status",
+                Politeness::Assertive => "alert",
+            },
+        )
+        .with_attribute("aria-live", politeness.as_str())
+        .with_attribute("aria-atomic", "true")
+        .with_attribute("data-slick-live", politeness.as_str())
+        .with_attribute("style", VISUALLY_HIDDEN)
+}
+
+fn region(politeness: Politeness) -> Option<Element> {
+    web_sys::window()?
+        .document()?
+        .query_selector(&format!("[data-slick-live=\"{}\"]", politeness.as_str()))
+        .ok()?
+}
+
+pub(crate) fn announce(text: &str, politeness: Politeness) {
+    let region = region(politeness);
+
+    #[cfg(feature = "dev")]
+    if region.is_none() {
+        crate::warn(&format!(
+            "no {} live region is rendered, dropping announcement",
+            politeness.as_str()
+        ));
+    }
+
+    if let Some(region) = region {
+        region.set_text_content(Some(text));
+    }
+}
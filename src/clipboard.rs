@@ -0,0 +1,49 @@
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Clipboard;
+
+use crate::{dom::Dispatch, AnyMessage, EffectError};
+
+/// Gets `Err` with the reason if the text could not be copied.
+pub(crate) type OnCopied = Box<dyn Fn(Result<(), EffectError>) -> Box<dyn AnyMessage>>;
+
+/// `navigator.clipboard` only exists in secure contexts, and the bindings
+/// don't know that.
+fn clipboard() -> Option<Clipboard> {
+    let navigator = web_sys::window()?.navigator();
+    let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into()).ok()?;
+
+    if clipboard.is_undefined() || clipboard.is_null() {
+        None
+    } else {
+        Some(clipboard.unchecked_into())
+    }
+}
+
+fn reason(error: JsValue) -> EffectError {
+    match error.dyn_into::<js_sys::Error>() {
+        // A `DOMException`, thrown without focus or permission.
+        Ok(error) if error.name() == "NotAllowedError" => EffectError::PermissionDenied,
+        Ok(error) => EffectError::Other(error.message().into()),
+        Err(error) => EffectError::Other(
+            error
+                .as_string()
+                .unwrap_or_else(|| "unknown error".to_string()),
+        ),
+    }
+}
+
+pub(crate) fn copy(text: String, on_copied: OnCopied, dispatch: Dispatch) {
+    let clipboard = match clipboard() {
+        Some(clipboard) => clipboard,
+        None => {
+            let unsupported = EffectError::Unsupported("the clipboard".to_string());
+            return dispatch(on_copied(Err(unsupported)));
+        }
+    };
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = JsFuture::from(clipboard.write_text(&text)).await;
+        dispatch(on_copied(result.map(|_| ()).map_err(reason)));
+    });
+}
@@ -0,0 +1,105 @@
+use crate::{Effect, Node, Subscription};
+
+pub trait Component {
+    type Message;
+
+    fn view(&self) -> Node;
+
+    /// Applies `msg` to the model. Messages that queue up, until the next
+    /// animation frame by default, see [`BatchMode`](crate::BatchMode), or
+    /// while the runtime is busy, like several dispatched right away by
+    /// effects, are all applied in the order they were dispatched before
+    /// `view` runs once. The effects they return are then run in the same
+    /// order, after the DOM is updated.
+    fn update(&mut self, msg: Self::Message) -> Option<Effect>;
+
+    /// Whether the component has everything it needs to render. A
+    /// [`Suspense`](crate::Suspense) around a component that is not ready
+    /// renders its fallback instead.
+    fn ready(&self) -> bool {
+        true
+    }
+
+    /// Whether leaving the page would lose something, like edits that
+    /// haven't been saved yet. Checked after every render, while it is true
+    /// the browser asks for confirmation before the page is closed or
+    /// reloaded.
+    ///
+    /// Browsers show their own wording and don't allow a custom message.
+    /// They may also skip the prompt if the user never interacted with the
+    /// page. Navigating with the [`router`](crate::router) doesn't leave the
+    /// page and is never interrupted.
+    fn unsaved_changes(&self) -> bool {
+        false
+    }
+
+    /// Called before the app navigates from the path `from` to `to`, through
+    /// an [`Effect::Navigate`] or by the browser going back, forward or to
+    /// another hash. Use `from` rather than
+    /// [`router::current_path`](crate::router::current_path), the browser may
+    /// already show `to`. Returning `false` stays where it is and re-renders, so
+    /// the component can remember `to`, ask whether to discard its changes
+    /// and continue with [`Effect::ForceNavigate`] once confirmed.
+    ///
+    /// When the browser went back or forward, the path that is shown is
+    /// pushed again, leaving an extra history entry behind. Closing or
+    /// reloading the page isn't covered, see [`Component::unsaved_changes`].
+    fn before_navigate(&mut self, _from: &str, _to: &str) -> bool {
+        true
+    }
+
+    /// What the component listens to outside of its view, like a timer while
+    /// something is running. Called after every render: subscriptions that
+    /// are new get started and ones that are no longer returned stopped, so
+    /// they follow the model without being started and stopped by hand.
+    ///
+    /// Only the component passed to [`run`](crate::run) is asked, not the
+    /// ones embedded with [`Node::custom`].
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![]
+    }
+
+    /// A stylesheet the component ships with, like rules for the classes its
+    /// view uses. The runtime adds it in a `<style>` the first time the
+    /// component is rendered, before its elements are mounted, and only once
+    /// per [`Component::name`], however many instances there are. It goes
+    /// into the document's head, or into the shadow root for
+    /// [`run_in_shadow`](crate::run_in_shadow).
+    ///
+    /// The stylesheet is never removed, it stays after the last instance is
+    /// gone and doesn't change if `css` returns something else later. Rules
+    /// apply to the whole document, see [`Scope`](crate::css::Scope) to keep
+    /// class names from clashing.
+    fn css(&self) -> &str {
+        ""
+    }
+
+    /// Identifies the component in debug output and dev-mode warnings.
+    /// Defaults to the full path of the type.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// A view function with its props, see [`Node::pure`].
+pub(crate) struct Pure<P, F> {
+    pub(crate) view: F,
+    pub(crate) props: P,
+}
+
+impl<P: Clone, F: Fn(P) -> Node> Component for Pure<P, F> {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        (self.view)(self.props.clone())
+    }
+
+    fn update(&mut self, _: ()) -> Option<Effect> {
+        None
+    }
+
+    /// The path of the function rather than of `Pure`.
+    fn name(&self) -> &str {
+        std::any::type_name::<F>()
+    }
+}
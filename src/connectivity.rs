@@ -0,0 +1,6 @@
+/// Whether the browser thinks it is online, as `navigator.onLine` reports it.
+/// `true` only means a network is connected, not that the server can be
+/// reached. Outside of a browser this is `true`.
+pub fn is_online() -> bool {
+    web_sys::window().is_none_or(|window| window.navigator().on_line())
+}
@@ -0,0 +1,346 @@
+use std::{any::type_name, collections::HashSet, fmt::Display};
+
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, ShadowRoot};
+
+use crate::{Node, NodeKind};
+
+/// A CSS length. Displays as what it would be written as in a stylesheet, so
+/// it can be passed to [`Node::with_style`](crate::Node::with_style) as is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Px(f64),
+    Em(f64),
+    Rem(f64),
+    Percent(f64),
+    /// Percent of the viewport width.
+    Vw(f64),
+    /// Percent of the viewport height.
+    Vh(f64),
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (value, unit) = match self {
+            Length::Px(value) => (value, "px"),
+            Length::Em(value) => (value, "em"),
+            Length::Rem(value) => (value, "rem"),
+            Length::Percent(value) => (value, "%"),
+            Length::Vw(value) => (value, "vw"),
+            Length::Vh(value) => (value, "vh"),
+        };
+
+        // Zero doesn't need a unit.
+        if *value == 0.0 {
+            write!(f, "0")
+        } else {
+            write!(f, "{}{}", value, unit)
+        }
+    }
+}
+
+/// `px(12)` is `"12px"`.
+pub fn px(value: impl Into<f64>) -> String {
+    Length::Px(value.into()).to_string()
+}
+
+/// `em(1.5)` is `"1.5em"`.
+pub fn em(value: impl Into<f64>) -> String {
+    Length::Em(value.into()).to_string()
+}
+
+/// `rem(2)` is `"2rem"`.
+pub fn rem(value: impl Into<f64>) -> String {
+    Length::Rem(value.into()).to_string()
+}
+
+/// `percent(50)` is `"50%"`.
+pub fn percent(value: impl Into<f64>) -> String {
+    Length::Percent(value.into()).to_string()
+}
+
+/// Sets the custom property `name` on the document's root element, or removes
+/// it for `None`. The leading `--` may be left out.
+pub(crate) fn set_var(name: &str, value: Option<&str>) {
+    let root = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+        .and_then(|root| root.dyn_into::<HtmlElement>().ok());
+    let root = match root {
+        Some(root) => root,
+        None => return,
+    };
+
+    let name = if name.starts_with("--") {
+        name.to_string()
+    } else {
+        format!("--{}", name)
+    };
+    let style = root.style();
+
+    let _ = match value {
+        Some(value) => style.set_property(&name, value),
+        None => style.remove_property(&name).map(|_| ()),
+    };
+}
+
+/// Removes all but the declaration that wins for every property of an
+/// inline style, in the order the winners were declared. Later declarations
+/// win unless an earlier one is `!important` and they aren't.
+pub(crate) fn normalize_style(style: &str) -> String {
+    let mut declarations: Vec<(String, &str)> = vec![];
+    let important = |declaration: &str| declaration.to_ascii_lowercase().ends_with("important");
+
+    for declaration in style.split(';').map(str::trim) {
+        let property = match declaration.split_once(':') {
+            Some((property, _)) => property.trim(),
+            None => continue,
+        };
+        // Custom properties are case-sensitive, everything else isn't.
+        let property = if property.starts_with("--") {
+            property.to_string()
+        } else {
+            property.to_ascii_lowercase()
+        };
+
+        if let Some(i) = declarations
+            .iter()
+            .position(|(known, _)| *known == property)
+        {
+            if important(declarations[i].1) && !important(declaration) {
+                continue;
+            }
+            declarations.remove(i);
+        }
+
+        declarations.push((property, declaration));
+    }
+
+    declarations
+        .iter()
+        .map(|(_, declaration)| *declaration)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The [`Component::css`](crate::Component::css) a runtime added so far.
+#[derive(Default)]
+pub(crate) struct ComponentStyles {
+    added: HashSet<String>,
+}
+
+impl ComponentStyles {
+    /// Adds the stylesheet `css` of the component `name` for the view in
+    /// `root`, unless it was added before, also by another runtime.
+    pub(crate) fn add(&mut self, root: &web_sys::Node, name: &str, css: &str) {
+        if css.is_empty() || self.added.contains(name) {
+            return;
+        }
+        self.added.insert(name.to_string());
+
+        let document = match web_sys::window().and_then(|window| window.document()) {
+            Some(document) => document,
+            None => return,
+        };
+        // Styles from the head don't reach into shadow roots.
+        let target: web_sys::Node = match root.dyn_ref::<ShadowRoot>() {
+            Some(shadow) => shadow.clone().into(),
+            None => match document.head() {
+                Some(head) => head.into(),
+                None => return,
+            },
+        };
+
+        let selector = format!(
+            "style[data-slick-component=\"{}\"]",
+            name.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        let exists = match target.dyn_ref::<ShadowRoot>() {
+            Some(shadow) => shadow.query_selector(&selector),
+            None => target
+                .unchecked_ref::<web_sys::Element>()
+                .query_selector(&selector),
+        };
+        if let Ok(Some(_)) = exists {
+            return;
+        }
+
+        if let Ok(style) = document.create_element("style") {
+            let _ = style.set_attribute("data-slick-component", name);
+            style.set_text_content(Some(css));
+            let _ = target.append_child(&style);
+        }
+    }
+
+    /// Adds the stylesheets of every component embedded in `view`.
+    pub(crate) fn add_embedded(&mut self, root: &web_sys::Node, view: &Node) {
+        if let NodeKind::Custom {
+            component,
+            rendered,
+            ..
+        } = &view.kind
+        {
+            self.add(root, component.name(), component.css());
+            self.add_embedded(root, rendered);
+        }
+        for child in &view.children {
+            self.add_embedded(root, child);
+        }
+    }
+}
+
+/// Makes class names unique to a component type by appending a suffix
+/// derived from the type's name. The suffix is the same on every run, so it
+/// can be referenced from stylesheets that are generated at build time too.
+///
+/// Most of the time [`scoped_classes!`](crate::scoped_classes) is more
+/// convenient than using this directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scope {
+    suffix: String,
+}
+
+/// FNV-1a, because the std hashers don't promise to stay the same across
+/// releases.
+fn hash(value: &str) -> u32 {
+    value.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+impl Scope {
+    pub fn of<C: ?Sized + 'static>() -> Self {
+        Self {
+            suffix: format!("{:06x}", hash(type_name::<C>()) & 0xff_ffff),
+        }
+    }
+
+    /// `button` becomes e.g. `button-a1b2c3`.
+    pub fn class(&self, name: &str) -> String {
+        format!("{}-{}", name, self.suffix)
+    }
+
+    /// Rewrites the class selectors for `names` in `stylesheet`, leaving
+    /// every other class alone.
+    pub fn css(&self, stylesheet: &str, names: &[&str]) -> String {
+        let mut scoped = String::with_capacity(stylesheet.len());
+        let mut rest = stylesheet;
+
+        while let Some(dot) = rest.find('.') {
+            scoped.push_str(&rest[..=dot]);
+            rest = &rest[dot + 1..];
+
+            let end = rest.find(|c| !is_ident(c)).unwrap_or(rest.len());
+            let name = &rest[..end];
+
+            if names.contains(&name) {
+                scoped.push_str(&self.class(name));
+            } else {
+                scoped.push_str(name);
+            }
+
+            rest = &rest[end..];
+        }
+
+        scoped.push_str(rest);
+        scoped
+    }
+}
+
+/// Declares a struct holding class names scoped to a component type, see
+/// [`Scope`].
+///
+/// ```ignore
+/// slick::scoped_classes! {
+///     struct Styles for Toolbar { button, active }
+/// }
+///
+/// let styles = Styles::new();
+/// Node::native("button").with_attribute("class", styles.button.as_str());
+/// // `.button { .. }` becomes `.button-a1b2c3 { .. }`.
+/// let stylesheet = styles.css(".button { color: red }");
+/// ```
+#[macro_export]
+macro_rules! scoped_classes {
+    ($vis:vis struct $name:ident for $component:ty { $($class:ident),* $(,)? }) => {
+        $vis struct $name {
+            $(pub $class: String,)*
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                let scope = $crate::css::Scope::of::<$component>();
+                Self {
+                    $($class: scope.class(stringify!($class)),)*
+                }
+            }
+
+            /// Scopes the declared classes in `stylesheet`.
+            pub fn css(&self, stylesheet: &str) -> String {
+                $crate::css::Scope::of::<$component>()
+                    .css(stylesheet, &[$(stringify!($class)),*])
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths_display_with_unit() {
+        assert_eq!(px(12), "12px");
+        assert_eq!(em(1.5), "1.5em");
+        assert_eq!(rem(2), "2rem");
+        assert_eq!(percent(50), "50%");
+        assert_eq!(Length::Vw(100.0).to_string(), "100vw");
+        assert_eq!(Length::Vh(-10.0).to_string(), "-10vh");
+    }
+
+    #[test]
+    fn zero_has_no_unit() {
+        assert_eq!(px(0), "0");
+        assert_eq!(Length::Percent(0.0).to_string(), "0");
+    }
+
+    #[test]
+    fn lengths_work_with_style() {
+        let node = Node::native("div").with_style("width", Length::Px(120.0));
+
+        assert_eq!(node.attributes["style"].as_text(), "width: 120px");
+    }
+
+    #[test]
+    fn later_declarations_win() {
+        assert_eq!(
+            normalize_style("color: red; margin: 0; COLOR: blue"),
+            "margin: 0; COLOR: blue"
+        );
+    }
+
+    #[test]
+    fn important_declarations_win() {
+        assert_eq!(
+            normalize_style("color: red !important; color: blue;"),
+            "color: red !important"
+        );
+        assert_eq!(
+            normalize_style("--Gap: 1px; --gap: 2px"),
+            "--Gap: 1px; --gap: 2px"
+        );
+    }
+
+    #[test]
+    fn to_html_deduplicates_styles() {
+        let node = Node::native("p")
+            .with_style("color", "red")
+            .with_style("color", "blue");
+
+        assert!(node.to_html().starts_with("<p style=\"color: blue\">"));
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::HtmlElement;
+
+use crate::{
+    runtime::{self, Instance},
+    AnyMessage, Component,
+};
+
+/// Where the id of the instance rendered into an element is stored on it.
+const INSTANCE_ID: &str = "__slickInstance";
+
+/// The class has to be written in JS, there is no way to extend
+/// `HTMLElement` from Rust.
+const CLASS: &str = "
+    return class extends HTMLElement {
+        static get observedAttributes() { return attributes; }
+        connectedCallback() { connected(this); }
+        disconnectedCallback() { disconnected(this); }
+        attributeChangedCallback(name, old, value) { changed(this, name, value); }
+    };
+";
+
+type Callback<A> = Closure<dyn FnMut(A)>;
+type Changed = Closure<dyn FnMut(HtmlElement, String, Option<String>)>;
+
+fn instance_id(element: &HtmlElement) -> Option<u32> {
+    js_sys::Reflect::get(element, &INSTANCE_ID.into())
+        .ok()?
+        .as_f64()
+        .map(|id| id as u32)
+}
+
+/// Registers a custom element named `name` (which has to contain a dash)
+/// that renders a component created by `create` into itself, so slick
+/// widgets can be used from plain HTML.
+///
+/// The element's `attributes` are how the page passes props: whenever one of
+/// them is set, changed or removed, the message returned by `on_attribute`
+/// for its name and new value goes through `update`. Attributes that are
+/// already set when the element is mounted are delivered right after the
+/// first render.
+///
+/// Lifecycle:
+/// - `connectedCallback` mounts a new component into the element.
+/// - `disconnectedCallback` drops the component and empties the element.
+///   Moving an element to another place in the document disconnects and
+///   connects it again, so it starts over with a fresh component.
+/// - `attributeChangedCallback` dispatches `on_attribute`.
+///
+/// Unlike [`run`](crate::run) custom elements don't touch the browser
+/// history, since the page they are embedded into owns it.
+pub fn define_element<C>(
+    name: &str,
+    attributes: &[&str],
+    create: impl Fn() -> C + 'static,
+    on_attribute: impl Fn(&str, Option<String>) -> C::Message + 'static,
+) where
+    C: Component + 'static,
+    C::Message: AnyMessage,
+{
+    define(name, attributes, create, on_attribute, false);
+}
+
+/// Like [`define_element`], but the component is rendered into a shadow
+/// root of the element, see [`run_in_shadow`](crate::run_in_shadow).
+pub fn define_shadow_element<C>(
+    name: &str,
+    attributes: &[&str],
+    create: impl Fn() -> C + 'static,
+    on_attribute: impl Fn(&str, Option<String>) -> C::Message + 'static,
+) where
+    C: Component + 'static,
+    C::Message: AnyMessage,
+{
+    define(name, attributes, create, on_attribute, true);
+}
+
+fn define<C>(
+    name: &str,
+    attributes: &[&str],
+    create: impl Fn() -> C + 'static,
+    on_attribute: impl Fn(&str, Option<String>) -> C::Message + 'static,
+    shadow: bool,
+) where
+    C: Component + 'static,
+    C::Message: AnyMessage,
+{
+    let instances: Rc<RefCell<HashMap<u32, Rc<Instance<C>>>>> =
+        Rc::new(RefCell::new(HashMap::new()));
+    let next_id = Rc::new(Cell::new(0));
+    let attributes: Vec<String> = attributes.iter().map(|name| name.to_string()).collect();
+    let on_attribute = Rc::new(on_attribute);
+
+    let connected: Callback<HtmlElement> = {
+        let instances = instances.clone();
+        let attributes = attributes.clone();
+        let on_attribute = on_attribute.clone();
+        Closure::wrap(Box::new(move |element: HtmlElement| {
+            let id = next_id.get() + 1;
+            next_id.set(id);
+            let _ = js_sys::Reflect::set(&element, &INSTANCE_ID.into(), &id.into());
+
+            let root = if shadow {
+                runtime::shadow_root(&element)
+            } else {
+                element.clone().into()
+            };
+            let instance = Rc::new(Instance::new(create(), root));
+            instances.borrow_mut().insert(id, instance.clone());
+
+            for name in &attributes {
+                if let Some(value) = element.get_attribute(name) {
+                    instance.dispatch(Box::new(on_attribute(name, Some(value))));
+                }
+            }
+        }) as Box<dyn FnMut(HtmlElement)>)
+    };
+
+    let disconnected: Callback<HtmlElement> = {
+        let instances = instances.clone();
+        Closure::wrap(Box::new(move |element: HtmlElement| {
+            let instance = instance_id(&element).and_then(|id| instances.borrow_mut().remove(&id));
+            // Unmounted outside of the borrow, nested elements of the same
+            // kind get disconnected along with it.
+            drop(instance);
+        }) as Box<dyn FnMut(HtmlElement)>)
+    };
+
+    let changed: Changed = Closure::wrap(Box::new(
+        move |element: HtmlElement, name: String, value: Option<String>| {
+            // Attributes set before the element is connected are picked up
+            // when it is.
+            // Cloned out, updating may connect or disconnect other elements.
+            let instance =
+                instance_id(&element).and_then(|id| instances.borrow().get(&id).cloned());
+
+            if let Some(instance) = instance {
+                instance.dispatch(Box::new(on_attribute(&name, value)));
+            }
+        },
+    )
+        as Box<dyn FnMut(HtmlElement, String, Option<String>)>);
+
+    let observed: js_sys::Array = attributes.iter().map(JsValue::from).collect();
+    let class =
+        js_sys::Function::new_with_args("attributes, connected, disconnected, changed", CLASS)
+            .apply(
+                &JsValue::NULL,
+                &js_sys::Array::of4(
+                    &observed,
+                    connected.as_ref(),
+                    disconnected.as_ref(),
+                    changed.as_ref(),
+                ),
+            )
+            .expect("the element class is valid JS");
+
+    let defined = web_sys::window()
+        .expect("no global `window` exists")
+        .custom_elements()
+        .define(name, class.unchecked_ref());
+
+    if defined.is_err() {
+        console_log!("failed to define the custom element `{}`", name);
+        return;
+    }
+
+    // Custom elements can't be undefined again.
+    connected.forget();
+    disconnected.forget();
+    changed.forget();
+}
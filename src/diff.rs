@@ -0,0 +1,617 @@
+use std::collections::HashSet;
+
+use crate::{Node, NodeAttributeValue, NodeKind};
+
+/// Child indices leading from the root of a tree down to a node.
+pub type Path = Vec<usize>;
+
+/// A single change that turns the DOM of an old tree into the DOM of a new
+/// one. Paths are only valid if the patches are applied in order.
+#[derive(Debug)]
+pub enum Patch<'a> {
+    /// Replaces the node at `path` with a freshly created `node`.
+    Replace {
+        path: Path,
+        node: &'a Node,
+    },
+    SetAttribute {
+        path: Path,
+        key: &'static str,
+        value: &'a NodeAttributeValue,
+    },
+    RemoveAttribute {
+        path: Path,
+        key: &'static str,
+    },
+    /// Changes the content of the text node at `path`, keeping the node.
+    SetText {
+        path: Path,
+        text: &'a str,
+    },
+    /// Appends `node` to the children of the element at `path`.
+    Append {
+        path: Path,
+        node: &'a Node,
+    },
+    /// Inserts `node` into the children of the element at `path`, so it ends
+    /// up at `index`.
+    Insert {
+        path: Path,
+        index: usize,
+        node: &'a Node,
+    },
+    /// Moves the child at `from` of the element at `path` to `to`, keeping
+    /// its DOM node.
+    Move {
+        path: Path,
+        from: usize,
+        to: usize,
+    },
+    /// Removes the node at `path`.
+    Remove {
+        path: Path,
+    },
+}
+
+/// Custom nodes don't exist in the DOM, only what they rendered does.
+pub(crate) fn resolve(mut node: &Node) -> &Node {
+    while let NodeKind::Custom { rendered, .. } = &node.kind {
+        node = rendered;
+    }
+    node
+}
+
+/// Computes the patches that turn `old` into `new`.
+pub fn diff<'a>(old: &Node, new: &'a Node) -> Vec<Patch<'a>> {
+    let mut patches = vec![];
+    diff_node(resolve(old), resolve(new), &mut vec![], &mut patches);
+    patches
+}
+
+fn diff_node<'a>(old: &Node, new: &'a Node, path: &mut Path, patches: &mut Vec<Patch<'a>>) {
+    let same_tag = match (&old.kind, &new.kind) {
+        (NodeKind::Native { tag: a }, NodeKind::Native { tag: b }) => a == b,
+        _ => false,
+    };
+
+    if !same_tag {
+        match (&old.kind, &new.kind) {
+            (NodeKind::Text(a), NodeKind::Text(b)) if a == b => {}
+            // Changing the text in place leaves the node alone, so a
+            // selection or screen reader position in it survives.
+            (NodeKind::Text(_), NodeKind::Text(text)) => patches.push(Patch::SetText {
+                path: path.clone(),
+                text,
+            }),
+            _ => patches.push(Patch::Replace {
+                path: path.clone(),
+                node: new,
+            }),
+        }
+
+        return;
+    }
+
+    for &key in old.attributes.keys() {
+        if present(old, key).is_some() && present(new, key).is_none() {
+            patches.push(Patch::RemoveAttribute {
+                path: path.clone(),
+                key,
+            });
+        }
+    }
+
+    for &key in new.attributes.keys() {
+        let value = match present(new, key) {
+            Some(value) => value,
+            None => continue,
+        };
+        // Compared as text, since that is all the DOM gets to see.
+        if present(old, key).is_some_and(|old| old.as_text() == value.as_text()) {
+            continue;
+        }
+
+        patches.push(Patch::SetAttribute {
+            path: path.clone(),
+            key,
+            value,
+        });
+    }
+
+    if keyed(&old.children) && keyed(&new.children) {
+        diff_keyed(&old.children, &new.children, path, patches);
+        return;
+    }
+
+    #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+    if let Some(warning) = keyless_list_warning(old, new) {
+        crate::warn(&warning);
+    }
+
+    for (i, (old, new)) in old.children.iter().zip(&new.children).enumerate() {
+        path.push(i);
+        diff_node(resolve(old), resolve(new), path, patches);
+        path.pop();
+    }
+
+    // Removing from the back keeps the indices of the remaining children
+    // valid.
+    for i in (new.children.len()..old.children.len()).rev() {
+        path.push(i);
+        patches.push(Patch::Remove { path: path.clone() });
+        path.pop();
+    }
+
+    for child in new.children.iter().skip(old.children.len()) {
+        patches.push(Patch::Append {
+            path: path.clone(),
+            node: resolve(child),
+        });
+    }
+}
+
+/// The value of the attribute `key` of `node`, unless it is missing or a
+/// false boolean, which isn't in the DOM either.
+fn present<'n>(node: &'n Node, key: &str) -> Option<&'n NodeAttributeValue> {
+    node.attributes.get(key).filter(|value| value.is_present())
+}
+
+/// Whether every child has a key and no key appears twice.
+fn keyed(children: &[Node]) -> bool {
+    let mut keys = HashSet::new();
+    children
+        .iter()
+        .all(|child| child.key.as_ref().is_some_and(|key| keys.insert(key)))
+}
+
+/// The warning for children of the same tag as `new` that changed in number
+/// since `old` and look like items rendered from a list without keys.
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+fn keyless_list_warning(old: &Node, new: &Node) -> Option<String> {
+    let tag = match &new.kind {
+        NodeKind::Native { tag } => tag,
+        _ => return None,
+    };
+
+    (old.children.len() != new.children.len() && is_keyless_list(&new.children)).then(|| {
+        format!(
+            "the children of a <{}> changed in number without keys, they are matched by \
+             position so every item after a change is patched, consider \
+             `Node::with_children_keyed`",
+            tag
+        )
+    })
+}
+
+/// Whether `children` look like items rendered from a list: several elements
+/// with the same tag and no keys.
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+fn is_keyless_list(children: &[Node]) -> bool {
+    let tag = |node: &Node| match &resolve(node).kind {
+        NodeKind::Native { tag } => Some(tag.clone()),
+        _ => None,
+    };
+
+    children.len() > 1
+        && tag(&children[0]).is_some()
+        && children
+            .iter()
+            .all(|child| child.key.is_none() && tag(child) == tag(&children[0]))
+}
+
+fn diff_keyed<'a>(old: &[Node], new: &'a [Node], path: &mut Path, patches: &mut Vec<Patch<'a>>) {
+    let key = |node: &Node| node.key.clone().unwrap_or_default();
+    let new_keys: HashSet<String> = new.iter().map(key).collect();
+
+    // Removing from the back keeps the indices of the remaining children
+    // valid.
+    for (i, _) in old
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, child)| !new_keys.contains(&key(child)))
+    {
+        path.push(i);
+        patches.push(Patch::Remove { path: path.clone() });
+        path.pop();
+    }
+
+    // The children as they are in the DOM while the patches are applied.
+    let mut current: Vec<&Node> = old
+        .iter()
+        .filter(|child| new_keys.contains(&key(child)))
+        .collect();
+
+    for (to, child) in new.iter().enumerate() {
+        match current[to..].iter().position(|old| old.key == child.key) {
+            Some(offset) => {
+                let from = to + offset;
+
+                if from != to {
+                    patches.push(Patch::Move {
+                        path: path.clone(),
+                        from,
+                        to,
+                    });
+                    let moved = current.remove(from);
+                    current.insert(to, moved);
+                }
+
+                path.push(to);
+                diff_node(resolve(current[to]), resolve(child), path, patches);
+                path.pop();
+            }
+            // New items at the end, like the latest line of a log, are
+            // appended, so several of them go into the DOM at once.
+            None if to == current.len() => {
+                patches.push(Patch::Append {
+                    path: path.clone(),
+                    node: resolve(child),
+                });
+                current.push(child);
+            }
+            None => {
+                patches.push(Patch::Insert {
+                    path: path.clone(),
+                    index: to,
+                    node: resolve(child),
+                });
+                current.insert(to, child);
+            }
+        }
+    }
+}
+
+/// Describes the patches that turn `old` into `new`, one per line, like
+/// `SetAttribute on div[0]/span[2]: class "red" → "blue"`, to find out why a
+/// render touched more than expected. Nodes are named by tag and position
+/// among their siblings, looked up in `old`, so after an insert or move in
+/// the same list the tag may be the one of the node there before.
+#[cfg(feature = "dev")]
+pub fn describe_diff(old: &Node, new: &Node) -> String {
+    let old = resolve(old);
+
+    diff(old, new)
+        .iter()
+        .map(|patch| match patch {
+            Patch::Replace { path, node } => {
+                format!(
+                    "Replace {} with {}",
+                    describe_path(old, path),
+                    describe_node(node)
+                )
+            }
+            Patch::SetAttribute { path, key, value } => {
+                let before = at(old, path)
+                    .and_then(|node| present(node, key))
+                    .map_or("nothing".to_string(), |value| {
+                        format!("{:?}", value.as_text())
+                    });
+                format!(
+                    "SetAttribute on {}: {} {} → {:?}",
+                    describe_path(old, path),
+                    key,
+                    before,
+                    value.as_text()
+                )
+            }
+            Patch::RemoveAttribute { path, key } => {
+                format!("RemoveAttribute on {}: {}", describe_path(old, path), key)
+            }
+            Patch::SetText { path, text } => {
+                let before = match at(old, path).map(|node| &node.kind) {
+                    Some(NodeKind::Text(before)) => format!("{:?}", before),
+                    _ => "?".to_string(),
+                };
+                format!(
+                    "SetText on {}: {} → {:?}",
+                    describe_path(old, path),
+                    before,
+                    text
+                )
+            }
+            Patch::Append { path, node } => {
+                format!(
+                    "Append {} to {}",
+                    describe_node(node),
+                    describe_path(old, path)
+                )
+            }
+            Patch::Insert { path, index, node } => format!(
+                "Insert {} into {} at {}",
+                describe_node(node),
+                describe_path(old, path),
+                index
+            ),
+            Patch::Move { path, from, to } => format!(
+                "Move child {} of {} to {}",
+                from,
+                describe_path(old, path),
+                to
+            ),
+            Patch::Remove { path } => format!("Remove {}", describe_path(old, path)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(feature = "dev")]
+fn at<'a>(root: &'a Node, path: &[usize]) -> Option<&'a Node> {
+    path.iter()
+        .try_fold(root, |node, &i| node.children.get(i).map(resolve))
+}
+
+/// `div[0]/span[2]`, with `?` for nodes that aren't in `root`.
+#[cfg(feature = "dev")]
+fn describe_path(root: &Node, path: &[usize]) -> String {
+    let tag = |node: Option<&Node>| match node.map(|node| &node.kind) {
+        Some(NodeKind::Native { tag }) => tag.clone(),
+        Some(NodeKind::Text(_)) => "#text".to_string(),
+        _ => "?".to_string(),
+    };
+
+    std::iter::once(format!("{}[0]", tag(Some(root))))
+        .chain(
+            (1..=path.len())
+                .map(|len| format!("{}[{}]", tag(at(root, &path[..len])), path[len - 1])),
+        )
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(feature = "dev")]
+fn describe_node(node: &Node) -> String {
+    match &resolve(node).kind {
+        NodeKind::Native { tag } => format!("<{}>", tag),
+        NodeKind::Text(text) => format!("text {:?}", text),
+        NodeKind::Custom { .. } => unreachable!("custom nodes are resolved"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The patches as short lines, to compare them without the nodes.
+    fn summary(old: &Node, new: &Node) -> Vec<String> {
+        diff(old, new)
+            .iter()
+            .map(|patch| match patch {
+                Patch::Replace { path, .. } => format!("Replace {:?}", path),
+                Patch::SetAttribute { path, key, value } => {
+                    format!("SetAttribute {:?} {}={}", path, key, value.as_text())
+                }
+                Patch::RemoveAttribute { path, key } => {
+                    format!("RemoveAttribute {:?} {}", path, key)
+                }
+                Patch::SetText { path, text } => format!("SetText {:?} {}", path, text),
+                Patch::Append { path, .. } => format!("Append {:?}", path),
+                Patch::Insert { path, index, .. } => format!("Insert {:?} {}", path, index),
+                Patch::Move { path, from, to } => format!("Move {:?} {} {}", path, from, to),
+                Patch::Remove { path } => format!("Remove {:?}", path),
+            })
+            .collect()
+    }
+
+    fn keyed_list(keys: &[&str]) -> Node {
+        Node::native("ul").with_children_keyed(
+            keys.iter().copied(),
+            |key| *key,
+            |key| Node::native("li").with_child(Node::text(key)),
+        )
+    }
+
+    #[test]
+    fn reordering_keyed_children_moves_them() {
+        let old = keyed_list(&["a", "b", "c"]);
+        let new = keyed_list(&["c", "a", "b"]);
+
+        assert_eq!(summary(&old, &new), ["Move [] 2 0"]);
+    }
+
+    #[test]
+    fn reversing_keyed_children_only_moves_them() {
+        let old = keyed_list(&["a", "b", "c"]);
+        let new = keyed_list(&["c", "b", "a"]);
+
+        assert_eq!(summary(&old, &new), ["Move [] 2 0", "Move [] 2 1"]);
+    }
+
+    #[test]
+    fn removing_keyed_child_keeps_the_others() {
+        let old = keyed_list(&["a", "b", "c"]);
+        let new = keyed_list(&["a", "c"]);
+
+        assert_eq!(summary(&old, &new), ["Remove [1]"]);
+    }
+
+    #[test]
+    fn inserting_keyed_child_in_the_middle() {
+        let old = keyed_list(&["a", "c"]);
+        let new = keyed_list(&["a", "b", "c"]);
+
+        assert_eq!(summary(&old, &new), ["Insert [] 1"]);
+    }
+
+    #[test]
+    fn only_changed_attributes_are_patched() {
+        let old = Node::native("a")
+            .href("/a")
+            .title("A")
+            .with_attribute("class", "link");
+        let new = Node::native("a")
+            .href("/b")
+            .with_attribute("class", "link")
+            .with_attribute("rel", "next");
+
+        assert_eq!(
+            summary(&old, &new),
+            [
+                "RemoveAttribute [] title",
+                "SetAttribute [] href=/b",
+                "SetAttribute [] rel=next"
+            ]
+        );
+    }
+
+    #[test]
+    fn changing_one_of_three_attributes_sets_only_that_one() {
+        let link = |title| {
+            Node::native("a")
+                .href("/a")
+                .title(title)
+                .with_attribute("class", "link")
+        };
+
+        assert_eq!(summary(&link("A"), &link("B")), ["SetAttribute [] title=B"]);
+    }
+
+    #[test]
+    fn unchanged_attributes_are_not_patched() {
+        let node = || Node::native("input").id("name").placeholder("Name");
+
+        assert!(summary(&node(), &node()).is_empty());
+    }
+
+    fn editor(value: &str, saves: usize) -> Node {
+        Node::native("form")
+            .with_child(Node::native("input").with_value(value).on_input(|_| ()))
+            .with_child(Node::native("p").with_child(Node::text_of(saves)))
+    }
+
+    #[test]
+    fn input_survives_unrelated_change() {
+        // Typing only changes the value property, which isn't patched, and
+        // the counter next to the input is patched in place.
+        let patches = summary(&editor("hel", 0), &editor("hello", 1));
+
+        assert_eq!(patches, ["SetText [1, 0] 1"]);
+    }
+
+    fn list(items: &[&str]) -> Node {
+        items.iter().fold(Node::native("ul"), |list, item| {
+            list.with_child(Node::native("li").with_child(Node::text(*item)))
+        })
+    }
+
+    #[test]
+    fn keyless_dynamic_list_warns() {
+        let warning = keyless_list_warning(&list(&["a", "b"]), &list(&["a", "b", "c"])).unwrap();
+
+        assert!(warning.starts_with("the children of a <ul> changed in number without keys"));
+    }
+
+    #[test]
+    fn keyed_or_unchanged_lists_do_not_warn() {
+        assert!(keyless_list_warning(&list(&["a", "b"]), &list(&["b", "a"])).is_none());
+        let keyed = keyed_list(&["a", "b", "c"]);
+        assert!(keyless_list_warning(&keyed_list(&["a"]), &keyed).is_none());
+        // Different tags are a layout rather than a list.
+        let layout = Node::native("div")
+            .with_child(Node::native("header"))
+            .with_child(Node::native("main"));
+        assert!(keyless_list_warning(&Node::native("div"), &layout).is_none());
+    }
+
+    #[test]
+    fn false_boolean_attributes_are_removed() {
+        let button = |disabled| Node::native("button").with_attribute("disabled", disabled);
+
+        assert_eq!(summary(&button(true), &button(false)), ["RemoveAttribute [] disabled"]);
+        assert_eq!(summary(&button(false), &button(true)), ["SetAttribute [] disabled=true"]);
+        assert!(summary(&button(false), &Node::native("button")).is_empty());
+        let text = Node::native("button").with_attribute("disabled", "false");
+        assert_eq!(summary(&button(false), &text), ["SetAttribute [] disabled=false"]);
+    }
+
+    #[test]
+    fn same_root_tag_is_patched_in_place() {
+        let old = Node::native("main")
+            .with_attribute("class", "light")
+            .with_child(Node::text("1"));
+        let new = Node::native("main")
+            .with_attribute("class", "dark")
+            .with_child(Node::text("2"))
+            .with_child(Node::native("footer"));
+
+        let patches = summary(&old, &new);
+        assert!(!patches.is_empty());
+        assert!(!patches.iter().any(|patch| patch == "Replace []"), "{:?}", patches);
+        assert_eq!(summary(&old, &Node::native("section")), ["Replace []"]);
+    }
+
+    fn counter(count: u32) -> Node {
+        Node::native("div")
+            .with_attribute("class", "counter")
+            .with_child(Node::native("span").with_child(Node::text_of(count)))
+            .with_child(Node::native("button").with_child(Node::text("+")))
+    }
+
+    #[test]
+    fn changed_number_only_patches_its_text() {
+        let (old, new) = (counter(9), counter(10));
+        let patches = diff(&old, &new);
+
+        // The text node is kept and so are the <span> and <div> around it.
+        assert_eq!(summary(&old, &new), ["SetText [0, 0] 10"]);
+        assert!(!patches.iter().any(|patch| matches!(patch, Patch::Replace { .. })));
+    }
+
+    #[test]
+    fn appending_to_a_long_keyed_list_is_one_append() {
+        let keys: Vec<String> = (0..5_001).map(|i| i.to_string()).collect();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+
+        let old = keyed_list(&keys[..5_000]);
+        let new = keyed_list(&keys);
+
+        assert_eq!(summary(&old, &new), ["Append []"]);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn describe_diff_names_nodes_by_path() {
+        let view = |color: &str, count: u32, items: &[&str]| {
+            Node::native("div")
+                .with_child(Node::native("h1").with_child(Node::text_of(count)))
+                .with_child(Node::native("p"))
+                .with_child(Node::native("span").with_attribute("class", color))
+                .with_child(keyed_list(items))
+        };
+        let old = view("red", 1, &["a"]);
+        let new = view("blue", 2, &["a", "b"]);
+
+        assert_eq!(
+            describe_diff(&old, &new),
+            [
+                "SetText on div[0]/h1[0]/#text[0]: \"1\" → \"2\"",
+                "SetAttribute on div[0]/span[2]: class \"red\" → \"blue\"",
+                "Append <li> to div[0]/ul[3]",
+            ]
+            .join("\n")
+        );
+        assert_eq!(describe_diff(&old, &old), "");
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn describe_diff_covers_added_and_removed_attributes() {
+        let old = Node::native("button").with_attribute("disabled", true);
+        let new = Node::native("button").with_attribute("title", "Save");
+
+        let description = describe_diff(&old, &new);
+        let mut lines: Vec<_> = description.lines().collect();
+        lines.sort_unstable();
+        assert_eq!(
+            lines,
+            [
+                "RemoveAttribute on button[0]: disabled",
+                "SetAttribute on button[0]: title nothing → \"Save\"",
+            ]
+        );
+        assert_eq!(
+            describe_diff(&old, &Node::native("a")),
+            "Replace button[0] with <a>"
+        );
+    }
+}
@@ -0,0 +1,859 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{
+    AnimationEvent, ClipboardEvent, Document, DocumentFragment, DragEvent, Element, Event,
+    EventTarget, HtmlElement, IntersectionObserver, IntersectionObserverEntry,
+    IntersectionObserverInit, KeyboardEvent, MouseEvent, ResizeObserver, ResizeObserverEntry,
+    TransitionEvent, WheelEvent,
+};
+
+use crate::{
+    diff::{resolve, Patch},
+    drag::MouseDrag,
+    file, AnimationHandler, AnyMessage, ClickHandler, DragHandler, FilesHandler, InputHandler,
+    KeyBinding, Modifiers, MouseDragHandler, Node, NodeKind, PositionHandler, ResizeHandler,
+    ScrollHandler, VisibilityHandler, VisibleHandler, WheelHandler, WheelInfo,
+};
+
+pub(crate) type Dispatch = Rc<dyn Fn(Box<dyn AnyMessage>)>;
+type Listener = Closure<dyn FnMut(Event)>;
+type ObserverCallback = Closure<dyn FnMut(js_sys::Array)>;
+
+/// A DOM listener that calls whatever handler is currently in the slot, so
+/// patched elements pick up the handlers of the new tree without being
+/// re-registered.
+struct Slot<H> {
+    handler: Rc<RefCell<Option<H>>>,
+    listener: Option<Listener>,
+}
+
+impl<H> Default for Slot<H> {
+    fn default() -> Self {
+        Self {
+            handler: Rc::new(RefCell::new(None)),
+            listener: None,
+        }
+    }
+}
+
+impl<H: Clone + 'static> Slot<H> {
+    fn sync(
+        &mut self,
+        handler: Option<&H>,
+        target: &EventTarget,
+        event: &str,
+        dispatch: &Dispatch,
+        call: fn(&H, &Event) -> Option<Box<dyn AnyMessage>>,
+    ) {
+        *self.handler.borrow_mut() = handler.cloned();
+
+        if self.listener.is_some() || handler.is_none() {
+            return;
+        }
+
+        let handler = self.handler.clone();
+        let dispatch = dispatch.clone();
+        let listener = Closure::wrap(Box::new(move |event: Event| {
+            // Cloned out of the slot, dispatching re-renders and refills it.
+            let handler = handler.borrow().clone();
+            if let Some(msg) = handler.and_then(|handler| call(&handler, &event)) {
+                dispatch(msg);
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        target
+            .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+            .unwrap();
+        self.listener = Some(listener);
+    }
+}
+
+/// Dispatches the `on_visible` message of an element through an
+/// `IntersectionObserver` whenever it comes into view, which is disconnected
+/// once the element is gone. Renders while it stays in view dispatch nothing.
+struct Visibility {
+    handler: Rc<RefCell<Option<VisibleHandler>>>,
+    observer: Option<(Element, IntersectionObserver, ObserverCallback)>,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self {
+            handler: Rc::new(RefCell::new(None)),
+            observer: None,
+        }
+    }
+}
+
+impl Visibility {
+    fn sync(&mut self, handler: Option<&VisibleHandler>, element: &Element, dispatch: &Dispatch) {
+        *self.handler.borrow_mut() = handler.cloned();
+
+        // Without a handler the element is forgotten, so a handler added
+        // later is told right away if the element is in view already.
+        if handler.is_none() {
+            return self.disconnect();
+        }
+        if matches!(&self.observer, Some((observed, ..)) if observed == element) {
+            return;
+        }
+
+        self.disconnect();
+
+        let handler = self.handler.clone();
+        let dispatch = dispatch.clone();
+        let was_visible = Cell::new(false);
+        let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            // Only the latest entry matters if several crossings are batched.
+            let visible = match entries.iter().last() {
+                Some(entry) => entry
+                    .unchecked_into::<IntersectionObserverEntry>()
+                    .is_intersecting(),
+                None => return,
+            };
+            let entered = visible && !was_visible.replace(visible);
+            let handler = handler.borrow().clone();
+
+            if let Some(handler) = handler.filter(|_| entered) {
+                dispatch(handler());
+            }
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        let observer = IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &IntersectionObserverInit::new(),
+        )
+        .unwrap();
+        observer.observe(element);
+        self.observer = Some((element.clone(), observer, callback));
+    }
+
+    fn disconnect(&mut self) {
+        if let Some((_, observer, _)) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+}
+
+impl Drop for Visibility {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// Dispatches the `on_intersect` message of an element whenever it
+/// crosses the threshold. Unlike [`Visibility`] the element is only observed
+/// once, so nothing is reported while it stays on the same side.
+#[derive(Default)]
+struct Intersection {
+    handler: Rc<RefCell<Option<VisibilityHandler>>>,
+    observer: Option<(f64, IntersectionObserver, ObserverCallback)>,
+}
+
+impl Intersection {
+    fn sync(
+        &mut self,
+        on_change: Option<&(f64, VisibilityHandler)>,
+        element: &Element,
+        dispatch: &Dispatch,
+    ) {
+        *self.handler.borrow_mut() = on_change.map(|(_, handler)| handler.clone());
+
+        let threshold = match on_change {
+            Some((threshold, _)) => *threshold,
+            None => return self.disconnect(),
+        };
+
+        if matches!(&self.observer, Some((observed, ..)) if *observed == threshold) {
+            return;
+        }
+
+        self.disconnect();
+
+        let handler = self.handler.clone();
+        let dispatch = dispatch.clone();
+        let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            // Only the latest entry matters if several crossings are batched.
+            let entry = match entries.iter().last() {
+                Some(entry) => entry.unchecked_into::<IntersectionObserverEntry>(),
+                None => return,
+            };
+            let visible = entry.is_intersecting() && entry.intersection_ratio() >= threshold;
+            let handler = handler.borrow().clone();
+
+            if let Some(handler) = handler {
+                dispatch(handler(visible));
+            }
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        let options = IntersectionObserverInit::new();
+        options.set_threshold_f64(threshold);
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
+                .unwrap();
+        observer.observe(element);
+        self.observer = Some((threshold, observer, callback));
+    }
+
+    fn disconnect(&mut self) {
+        if let Some((_, observer, _)) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+}
+
+impl Drop for Intersection {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// Dispatches the `on_resize` message of an element through a
+/// `ResizeObserver`.
+#[derive(Default)]
+struct Resize {
+    handler: Rc<RefCell<Option<ResizeHandler>>>,
+    observer: Option<(ResizeObserver, ObserverCallback)>,
+}
+
+impl Resize {
+    fn sync(&mut self, handler: Option<&ResizeHandler>, element: &Element, dispatch: &Dispatch) {
+        *self.handler.borrow_mut() = handler.cloned();
+
+        if handler.is_none() {
+            return self.disconnect();
+        }
+
+        if self.observer.is_some() {
+            return;
+        }
+
+        let handler = self.handler.clone();
+        let dispatch = dispatch.clone();
+        let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            let rect = match entries.iter().last() {
+                Some(entry) => entry.unchecked_into::<ResizeObserverEntry>().content_rect(),
+                None => return,
+            };
+            let handler = handler.borrow().clone();
+
+            if let Some(handler) = handler {
+                dispatch(handler(rect.width(), rect.height()));
+            }
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        // Throws in browsers that don't support it.
+        if let Ok(observer) = ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+            observer.observe(element);
+            self.observer = Some((observer, callback));
+        }
+    }
+
+    fn disconnect(&mut self) {
+        if let Some((observer, _)) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+}
+
+impl Drop for Resize {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// The `mousemove` and `mouseup` listeners a drag adds to the document.
+type DragListeners = (Document, Listener, Listener);
+
+/// Dispatches the `on_mouse_drag` messages of an element. The listeners
+/// following the pointer are added to the document on `mousedown` and removed
+/// again on `mouseup`.
+#[derive(Default)]
+struct MouseDragging {
+    handler: Rc<RefCell<Option<MouseDragHandler>>>,
+    listener: Option<Listener>,
+    dragging: Rc<RefCell<Option<DragListeners>>>,
+}
+
+impl MouseDragging {
+    fn sync(&mut self, handler: Option<&MouseDragHandler>, element: &Element, dispatch: &Dispatch) {
+        *self.handler.borrow_mut() = handler.cloned();
+
+        if self.listener.is_some() || handler.is_none() {
+            return;
+        }
+
+        let document = match element.owner_document() {
+            Some(document) => document,
+            None => return,
+        };
+        let handler = self.handler.clone();
+        let dragging = self.dragging.clone();
+        let dispatch = dispatch.clone();
+        let listener = Closure::wrap(Box::new(move |event: Event| {
+            let event = match event.dyn_into::<MouseEvent>() {
+                Ok(event) if event.button() == 0 => event,
+                _ => return,
+            };
+            // Keeps the drag from selecting text.
+            event.prevent_default();
+            // A drag whose button was released outside of the window never
+            // saw its `mouseup`.
+            release(&dragging);
+
+            let on_move = follow(&handler, &dispatch, MouseDrag::Move, None);
+            let on_up = follow(&handler, &dispatch, MouseDrag::End, Some(dragging.clone()));
+            for (event, listener) in [("mousemove", &on_move), ("mouseup", &on_up)] {
+                document
+                    .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                    .unwrap();
+            }
+            *dragging.borrow_mut() = Some((document.clone(), on_move, on_up));
+
+            let handler = handler.borrow().clone();
+            if let Some(handler) = handler {
+                dispatch(handler(MouseDrag::Start((
+                    event.client_x(),
+                    event.client_y(),
+                ))));
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        element
+            .add_event_listener_with_callback("mousedown", listener.as_ref().unchecked_ref())
+            .unwrap();
+        self.listener = Some(listener);
+    }
+}
+
+impl Drop for MouseDragging {
+    fn drop(&mut self) {
+        release(&self.dragging);
+    }
+}
+
+/// A document listener reporting the pointer position as `step`, which first
+/// ends the drag if `dragging` is given.
+fn follow(
+    handler: &Rc<RefCell<Option<MouseDragHandler>>>,
+    dispatch: &Dispatch,
+    step: fn((i32, i32)) -> MouseDrag,
+    dragging: Option<Rc<RefCell<Option<DragListeners>>>>,
+) -> Listener {
+    let handler = handler.clone();
+    let dispatch = dispatch.clone();
+    Closure::wrap(Box::new(move |event: Event| {
+        if let Some(dragging) = &dragging {
+            release(dragging);
+        }
+        let event = match event.dyn_ref::<MouseEvent>() {
+            Some(event) => event,
+            None => return,
+        };
+        let handler = handler.borrow().clone();
+        if let Some(handler) = handler {
+            dispatch(handler(step((event.client_x(), event.client_y()))));
+        }
+    }) as Box<dyn FnMut(Event)>)
+}
+
+/// Removes the document listeners of the drag in progress, if any.
+fn release(dragging: &RefCell<Option<DragListeners>>) {
+    let listeners = dragging.borrow_mut().take();
+    if let Some((document, on_move, on_up)) = listeners {
+        for (event, listener) in [("mousemove", &on_move), ("mouseup", &on_up)] {
+            let _ = document
+                .remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+        }
+    }
+}
+
+#[derive(Default)]
+struct Listeners {
+    click: Slot<ClickHandler>,
+    double_click: Slot<ClickHandler>,
+    context_menu: Slot<PositionHandler>,
+    wheel: Slot<(WheelHandler, bool)>,
+    key: Slot<Vec<KeyBinding>>,
+    input: Slot<InputHandler>,
+    blur: Slot<ClickHandler>,
+    paste: Slot<(InputHandler, bool)>,
+    copy: Slot<(ClickHandler, bool)>,
+    scroll: Slot<ScrollHandler>,
+    visibility: Visibility,
+    intersection: Intersection,
+    resize: Resize,
+    drag_start: Slot<(usize, DragHandler)>,
+    drag_over: Slot<DragHandler>,
+    drop: Slot<DragHandler>,
+    mouse_drag: MouseDragging,
+    files: Slot<FilesHandler>,
+    transition_end: Slot<AnimationHandler>,
+    animation_end: Slot<AnimationHandler>,
+}
+
+/// The type the index of an item dragged from an `on_drag_start` is stored
+/// under, which tells those drags apart from ones of files or text.
+const DRAG_INDEX: &str = "application/x-slick-index";
+
+thread_local! {
+    /// The index of the item being dragged. The data of a drag can only be
+    /// read once it's dropped, only its types are known before.
+    static DRAGGED: Cell<usize> = const { Cell::new(0) };
+}
+
+fn data_transfer(event: &Event) -> Option<web_sys::DataTransfer> {
+    event.dyn_ref::<DragEvent>()?.data_transfer()
+}
+
+/// The index of the dragged item if the drag started at an `on_drag_start`.
+fn dragged_index(event: &Event) -> Option<usize> {
+    let types = data_transfer(event)?.types();
+    types
+        .includes(&DRAG_INDEX.into(), 0)
+        .then(|| DRAGGED.with(Cell::get))
+}
+
+fn event_value(event: &Event) -> String {
+    event
+        .target()
+        .map(|target| value_of(&target))
+        .unwrap_or_default()
+}
+
+/// The `value` property of an input, select or textarea.
+fn value_of(target: &JsValue) -> String {
+    js_sys::Reflect::get(target, &"value".into())
+        .ok()
+        .and_then(|value| value.as_string())
+        .unwrap_or_default()
+}
+
+/// The first element matching `selector` inside of `root`, which is the
+/// element or shadow root a component is rendered into.
+pub(crate) fn query_selector(root: &web_sys::Node, selector: &str) -> Option<Element> {
+    let element = if let Some(root) = root.dyn_ref::<Element>() {
+        root.query_selector(selector)
+    } else if let Some(root) = root.dyn_ref::<DocumentFragment>() {
+        root.query_selector(selector)
+    } else {
+        return None;
+    };
+
+    element.ok().flatten()
+}
+
+fn focus_if_sibling_has_focus(element: &Element) {
+    let active = element
+        .owner_document()
+        .and_then(|document| document.active_element());
+    let parent = element.parent_element();
+
+    if let (Some(active), Some(parent)) = (active, parent) {
+        let sibling = active.parent_element().is_some_and(|other| other == parent);
+        if sibling && active != *element {
+            if let Some(element) = element.dyn_ref::<HtmlElement>() {
+                let _ = element.focus();
+            }
+        }
+    }
+}
+
+/// Whether `event` was fired on the element it is handled on, rather than
+/// bubbling up from a descendant.
+fn is_own(event: &Event) -> bool {
+    event.target().map(JsValue::from) == event.current_target().map(JsValue::from)
+}
+
+/// Mirrors the DOM created for a [`Node`], holding on to its listeners.
+pub(crate) struct Mounted {
+    pub(crate) dom: web_sys::Node,
+    listeners: Listeners,
+    children: Vec<Mounted>,
+}
+
+impl Mounted {
+    /// Creates the DOM for `node`. Listeners are attached by
+    /// [`Mounted::sync`].
+    pub(crate) fn create(node: &Node, document: &Document) -> Self {
+        let node = resolve(node);
+
+        let tag = match &node.kind {
+            NodeKind::Native { tag } => tag,
+            NodeKind::Text(value) => {
+                return Self {
+                    dom: document.create_text_node(value).into(),
+                    listeners: Listeners::default(),
+                    children: vec![],
+                }
+            }
+            NodeKind::Custom { .. } => unreachable!("custom nodes are resolved"),
+        };
+
+        let element = document.create_element(tag).unwrap();
+
+        for (key, val) in node.attributes.iter().filter(|(_, val)| val.is_present()) {
+            element.set_attribute(key, &val.as_text()).unwrap();
+        }
+
+        // The children go in through one fragment, like appended ones in
+        // `apply`, so the element changes once however many there are.
+        let fragment = document.create_document_fragment();
+        let children = node
+            .children
+            .iter()
+            .map(|child| {
+                let child = Self::create(child, document);
+                fragment.append_child(&child.dom).unwrap();
+                child
+            })
+            .collect();
+        element.append_child(&fragment).unwrap();
+
+        Self {
+            dom: element.into(),
+            listeners: Listeners::default(),
+            children,
+        }
+    }
+
+    fn at(&mut self, path: &[usize]) -> &mut Mounted {
+        path.iter()
+            .fold(self, |mounted, &index| &mut mounted.children[index])
+    }
+
+    fn element(&mut self, path: &[usize]) -> Element {
+        self.at(path).dom.clone().unchecked_into()
+    }
+
+    /// Applies `patches`, moving every node that gets taken out of the DOM
+    /// into `removed`.
+    pub(crate) fn apply(
+        &mut self,
+        patches: Vec<Patch>,
+        document: &Document,
+        removed: &mut Vec<Mounted>,
+    ) {
+        let mut patches = patches.into_iter().peekable();
+
+        while let Some(patch) = patches.next() {
+            match patch {
+                Patch::Replace { path, node } => {
+                    let replacement = Self::create(node, document);
+                    let old = self.at(&path);
+
+                    if let Some(parent) = old.dom.parent_node() {
+                        parent.replace_child(&replacement.dom, &old.dom).unwrap();
+                    }
+
+                    removed.push(std::mem::replace(old, replacement));
+                }
+                Patch::SetAttribute { path, key, value } => {
+                    self.element(&path)
+                        .set_attribute(key, &value.as_text())
+                        .unwrap();
+                }
+                Patch::RemoveAttribute { path, key } => {
+                    self.element(&path).remove_attribute(key).unwrap();
+                }
+                Patch::SetText { path, text } => self.at(&path).dom.set_node_value(Some(text)),
+                Patch::Append { path, node } => {
+                    let mut children = vec![Self::create(node, document)];
+
+                    // Children appended to the same parent in a row, like
+                    // the new items of a list, go in through one fragment,
+                    // so the live DOM only changes once.
+                    while let Some(Patch::Append { path: next, .. }) = patches.peek() {
+                        if *next != path {
+                            break;
+                        }
+                        if let Some(Patch::Append { node, .. }) = patches.next() {
+                            children.push(Self::create(node, document));
+                        }
+                    }
+
+                    let parent = self.at(&path);
+                    if let [child] = &children[..] {
+                        parent.dom.append_child(&child.dom).unwrap();
+                    } else {
+                        let fragment = document.create_document_fragment();
+                        for child in &children {
+                            fragment.append_child(&child.dom).unwrap();
+                        }
+                        parent.dom.append_child(&fragment).unwrap();
+                    }
+                    parent.children.extend(children);
+                }
+                Patch::Insert { path, index, node } => {
+                    let child = Self::create(node, document);
+                    let parent = self.at(&path);
+                    let before = parent
+                        .children
+                        .get(index)
+                        .map(|sibling| sibling.dom.clone());
+                    parent
+                        .dom
+                        .insert_before(&child.dom, before.as_ref())
+                        .unwrap();
+                    parent.children.insert(index, child);
+                }
+                Patch::Move { path, from, to } => {
+                    let parent = self.at(&path);
+                    let child = parent.children.remove(from);
+                    let before = parent.children.get(to).map(|sibling| sibling.dom.clone());
+                    parent
+                        .dom
+                        .insert_before(&child.dom, before.as_ref())
+                        .unwrap();
+                    parent.children.insert(to, child);
+                }
+                Patch::Remove { mut path } => {
+                    let index = path.pop().expect("the root is replaced, not removed");
+                    let parent = self.at(&path);
+                    let child = parent.children.remove(index);
+                    parent.dom.remove_child(&child.dom).unwrap();
+                    removed.push(child);
+                }
+            }
+        }
+    }
+
+    /// Points the listeners at the handlers of `node`, which has to be the
+    /// tree the DOM was just created or patched from. Also returns the
+    /// element of the first focus trap in the tree.
+    pub(crate) fn sync(
+        &mut self,
+        node: &Node,
+        dispatch: &Dispatch,
+        focus_trap: &mut Option<Element>,
+    ) {
+        let node = resolve(node);
+
+        if let Some(element) = self.dom.dyn_ref::<Element>() {
+            let listeners = &mut self.listeners;
+            listeners.click.sync(
+                node.on_click.as_ref(),
+                element,
+                "click",
+                dispatch,
+                |on_click, _| Some(on_click()),
+            );
+            listeners.double_click.sync(
+                node.on_double_click.as_ref(),
+                element,
+                "dblclick",
+                dispatch,
+                |on_double_click, _| Some(on_double_click()),
+            );
+            listeners.context_menu.sync(
+                node.on_context_menu.as_ref(),
+                element,
+                "contextmenu",
+                dispatch,
+                |on_context_menu, event| {
+                    event.prevent_default();
+                    let event = event.dyn_ref::<MouseEvent>()?;
+                    Some(on_context_menu((event.client_x(), event.client_y())))
+                },
+            );
+            listeners.wheel.sync(
+                node.on_wheel.as_ref(),
+                element,
+                "wheel",
+                dispatch,
+                |(on_wheel, prevent_default), event| {
+                    if *prevent_default {
+                        event.prevent_default();
+                    }
+                    let event = event.dyn_ref::<WheelEvent>()?;
+                    Some(on_wheel(WheelInfo {
+                        delta_x: event.delta_x(),
+                        delta_y: event.delta_y(),
+                        ctrl_key: event.ctrl_key(),
+                        shift_key: event.shift_key(),
+                        alt_key: event.alt_key(),
+                        meta_key: event.meta_key(),
+                    }))
+                },
+            );
+            listeners.key.sync(
+                Some(&node.on_key).filter(|bindings| !bindings.is_empty()),
+                element,
+                "keydown",
+                dispatch,
+                |bindings, event| {
+                    let event = event.dyn_ref::<KeyboardEvent>()?;
+                    let pressed = Modifiers {
+                        ctrl: event.ctrl_key(),
+                        shift: event.shift_key(),
+                        alt: event.alt_key(),
+                        meta: event.meta_key(),
+                    };
+                    let (_, _, f) = bindings.iter().find(|(key, modifiers, _)| {
+                        *modifiers == pressed && key.eq_ignore_ascii_case(&event.key())
+                    })?;
+                    event.prevent_default();
+                    Some(f())
+                },
+            );
+            listeners.input.sync(
+                node.on_input.as_ref(),
+                element,
+                "input",
+                dispatch,
+                |on_input, event| Some(on_input(event_value(event))),
+            );
+            listeners.blur.sync(
+                node.on_blur.as_ref(),
+                element,
+                "blur",
+                dispatch,
+                |on_blur, _| Some(on_blur()),
+            );
+            listeners.paste.sync(
+                node.on_paste.as_ref(),
+                element,
+                "paste",
+                dispatch,
+                |(on_paste, prevent_default), event| {
+                    if *prevent_default {
+                        event.prevent_default();
+                    }
+                    let text = event
+                        .dyn_ref::<ClipboardEvent>()
+                        .and_then(ClipboardEvent::clipboard_data)
+                        .and_then(|data| data.get_data("text/plain").ok())
+                        .unwrap_or_default();
+                    Some(on_paste(text))
+                },
+            );
+            listeners.copy.sync(
+                node.on_copy.as_ref(),
+                element,
+                "copy",
+                dispatch,
+                |(on_copy, prevent_default), event| {
+                    if *prevent_default {
+                        event.prevent_default();
+                    }
+                    Some(on_copy())
+                },
+            );
+            listeners.scroll.sync(
+                node.on_scroll.as_ref(),
+                element,
+                "scroll",
+                dispatch,
+                |on_scroll, event| {
+                    let top = event.target()?.dyn_into::<Element>().ok()?.scroll_top();
+                    Some(on_scroll(top))
+                },
+            );
+
+            listeners.drag_start.sync(
+                node.on_drag_start.as_ref(),
+                element,
+                "dragstart",
+                dispatch,
+                |(index, on_drag_start), event| {
+                    // Firefox doesn't start a drag without any data.
+                    if let Some(data) = data_transfer(event) {
+                        let _ = data.set_data("text/plain", "");
+                        let _ = data.set_data(DRAG_INDEX, &index.to_string());
+                    }
+                    DRAGGED.with(|dragged| dragged.set(*index));
+                    Some(on_drag_start(*index))
+                },
+            );
+            listeners.drag_over.sync(
+                node.on_drag_over.as_ref(),
+                element,
+                "dragover",
+                dispatch,
+                |on_drag_over, event| {
+                    let from = dragged_index(event)?;
+                    event.prevent_default();
+                    Some(on_drag_over(from))
+                },
+            );
+            listeners.drop.sync(
+                node.on_drop.as_ref(),
+                element,
+                "drop",
+                dispatch,
+                |on_drop, event| {
+                    let from = dragged_index(event)?;
+                    event.prevent_default();
+                    Some(on_drop(from))
+                },
+            );
+            listeners.files.sync(
+                node.on_files.as_ref(),
+                element,
+                "change",
+                dispatch,
+                |on_files, event| Some(on_files(file::selected(event))),
+            );
+            listeners.transition_end.sync(
+                node.on_transition_end.as_ref(),
+                element,
+                "transitionend",
+                dispatch,
+                |on_transition_end, event| {
+                    let event = event
+                        .dyn_ref::<TransitionEvent>()
+                        .filter(|_| is_own(event))?;
+                    Some(on_transition_end(event.property_name()))
+                },
+            );
+            listeners.animation_end.sync(
+                node.on_animation_end.as_ref(),
+                element,
+                "animationend",
+                dispatch,
+                |on_animation_end, event| {
+                    let event = event
+                        .dyn_ref::<AnimationEvent>()
+                        .filter(|_| is_own(event))?;
+                    Some(on_animation_end(event.animation_name()))
+                },
+            );
+            listeners
+                .visibility
+                .sync(node.on_visible.as_ref(), element, dispatch);
+            listeners
+                .intersection
+                .sync(node.on_intersect.as_ref(), element, dispatch);
+            listeners
+                .resize
+                .sync(node.on_resize.as_ref(), element, dispatch);
+            listeners
+                .mouse_drag
+                .sync(node.on_mouse_drag.as_ref(), element, dispatch);
+
+            // Only written when it differs, writing moves the caret to the
+            // end.
+            if let Some(value) = &node.value {
+                if value_of(element) != *value {
+                    let _ = js_sys::Reflect::set(element, &"value".into(), &value.into());
+                }
+            }
+
+            if node.roving_focus {
+                focus_if_sibling_has_focus(element);
+            }
+
+            if node.focus_trap && focus_trap.is_none() {
+                *focus_trap = Some(element.clone());
+            }
+        }
+
+        for (mounted, child) in self.children.iter_mut().zip(&node.children) {
+            mounted.sync(child, dispatch, focus_trap);
+        }
+    }
+}
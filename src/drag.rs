@@ -0,0 +1,37 @@
+/// Moves the item at `from` so it ends up at index `to`, shifting the items
+/// in between. Out-of-range indices leave `items` untouched.
+///
+/// This is the reordering a sortable list needs when an item dragged from
+/// `from` is dropped onto the item at `to`.
+pub fn reorder<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from >= items.len() || to >= items.len() {
+        return;
+    }
+
+    let item = items.remove(from);
+    items.insert(to, item);
+}
+
+/// A step of a mouse drag reported to [`Node::on_mouse_drag`], with the
+/// `(x, y)` position of the pointer relative to the viewport in pixels.
+///
+/// [`Node::on_mouse_drag`]: crate::Node::on_mouse_drag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MouseDrag {
+    /// The left button went down on the element.
+    Start((i32, i32)),
+    /// The mouse moved anywhere on the page while the button is held.
+    Move((i32, i32)),
+    /// The button was released, wherever the pointer is.
+    End((i32, i32)),
+}
+
+impl MouseDrag {
+    pub fn position(&self) -> (i32, i32) {
+        match *self {
+            MouseDrag::Start(position) | MouseDrag::Move(position) | MouseDrag::End(position) => {
+                position
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::{any::Any, future::Future, pin::Pin};
+
+use crate::{
+    clipboard::OnCopied,
+    file::{OnBytes, OnText},
+    http::{OnProgress, OnUploaded},
+    measure::OnRect,
+    router::OnLocation,
+    AnyMessage, WebFile,
+};
+
+/// Why an effect failed, shared by the callbacks of every effect that can.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EffectError {
+    /// No response arrived, like when offline or blocked by CORS.
+    Network,
+    /// The server answered with a 404.
+    NotFound,
+    /// The server answered with a 401 or 403, or the browser denied access,
+    /// like to the clipboard without a user gesture.
+    PermissionDenied,
+    /// The server answered with another status outside of 200..300.
+    Status(u16),
+    /// The result arrived but couldn't be read.
+    Decode(String),
+    /// The browser doesn't offer the API the effect needs.
+    Unsupported(String),
+    /// Anything else, with the browser's description.
+    Other(String),
+}
+
+impl EffectError {
+    /// The error for an HTTP response with `status` outside of 200..300.
+    pub(crate) fn from_status(status: u16) -> Self {
+        match status {
+            0 => EffectError::Network,
+            401 | 403 => EffectError::PermissionDenied,
+            404 => EffectError::NotFound,
+            status => EffectError::Status(status),
+        }
+    }
+}
+
+impl std::fmt::Display for EffectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EffectError::Network => write!(f, "the network request failed"),
+            EffectError::NotFound => write!(f, "not found"),
+            EffectError::PermissionDenied => write!(f, "permission denied"),
+            EffectError::Status(status) => write!(f, "the server responded with {}", status),
+            EffectError::Decode(reason) => write!(f, "couldn't decode the result: {}", reason),
+            EffectError::Unsupported(api) => write!(f, "{} is unavailable", api),
+            EffectError::Other(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for EffectError {}
+
+/// Hands a message to the rest of an [`Effect::then`] chain, or gives it back
+/// if it isn't the result the chain waits for.
+pub(crate) type OnResult =
+    Box<dyn FnMut(Box<dyn AnyMessage>) -> Result<Effect, Box<dyn AnyMessage>>>;
+
+pub enum Effect {
+    /// Pushes a new history entry for `path` and re-renders, unless
+    /// [`Component::before_navigate`](crate::Component::before_navigate)
+    /// blocks it. A fragment, like in `/docs#usage`, scrolls to the element
+    /// with that id, as soon as a render created it. The same goes for the
+    /// URL the page is loaded with.
+    Navigate(String),
+    /// Like [`Effect::Navigate`], but without asking `before_navigate`, to
+    /// continue a navigation it blocked.
+    ForceNavigate(String),
+    /// Replaces the URL of the current history entry, like to keep filters
+    /// in the query string. Nothing is rendered, scrolled or added to the
+    /// history, routes see the new path on the next render.
+    ReplaceUrl(String),
+    /// Dispatches the message `on_value` returns for the current location,
+    /// like to restore state from the query string at startup.
+    GetLocation { on_value: OnLocation },
+    /// Has screen readers announce the text through the rendered
+    /// [`live_region`](crate::aria::live_region) with polite priority.
+    Announce(String),
+    /// Like [`Effect::Announce`], but interrupts whatever the screen reader
+    /// is currently saying.
+    AnnounceAssertive(String),
+    /// Reads the contents of `file` as UTF-8 and dispatches the message
+    /// returned by `on_load`, which gets why reading failed instead, like
+    /// when the file was deleted after it was picked.
+    ReadFileText { file: WebFile, on_load: OnText },
+    /// Reads the raw contents of `file` and dispatches the message returned
+    /// by `on_load`, with the contents or why they couldn't be read.
+    ReadFileBytes { file: WebFile, on_load: OnBytes },
+    /// POSTs `fields` and `files` to `url` as `multipart/form-data`. Every
+    /// file is sent under the field name it is paired with.
+    ///
+    /// `on_progress` is called as the body goes out, `on_uploaded` once with
+    /// the response body or why the request failed.
+    Upload {
+        url: String,
+        fields: Vec<(String, String)>,
+        files: Vec<(String, WebFile)>,
+        on_progress: Option<OnProgress>,
+        on_uploaded: OnUploaded,
+    },
+    /// Writes the text to the clipboard and dispatches the message returned
+    /// by the callback. Copying fails where the Clipboard API is unavailable,
+    /// like pages that aren't served over HTTPS.
+    CopyToClipboard(String, OnCopied),
+    /// Dispatches `msg` once after `ms` milliseconds. Nothing repeats, to do
+    /// something periodically return another delay when `msg` comes in.
+    ///
+    /// Pending delays are cancelled when the component is unmounted.
+    Delay { ms: i32, msg: Box<dyn AnyMessage> },
+    /// Starts playing the `<audio>` or `<video>` element matching the
+    /// selector. Nothing happens if there is none or the browser refuses to
+    /// play, like when autoplay is blocked before the user interacted with
+    /// the page.
+    MediaPlay(String),
+    /// Pauses the `<audio>` or `<video>` element matching the selector.
+    MediaPause(String),
+    /// Jumps to `seconds` into the `<audio>` or `<video>` element matching
+    /// `selector`.
+    MediaSeek { selector: String, seconds: f64 },
+    /// Measures the element matching `selector` and dispatches the message
+    /// returned by `on_rect`, like to position a popover next to it. Effects
+    /// run after the render, so the rect is the one of the current view.
+    /// Nothing is dispatched if no element matches.
+    Measure { selector: String, on_rect: OnRect },
+    /// Sets the `content` of the `<meta>` tag for `key` in the document's
+    /// head, creating the tag if there is none. Keys starting with `og:` are
+    /// Open Graph properties, like `og:title` or `og:image`, anything else is
+    /// a name, like `description`.
+    ///
+    /// Crawlers that don't run JavaScript only see the tags in the served
+    /// HTML.
+    SetMeta { key: String, content: String },
+    /// Sets the title of the document, shown in the tab and the history.
+    SetTitle(String),
+    /// Sets the CSS custom property `name`, like `--accent`, on the root
+    /// element of the document, where every stylesheet can read it with
+    /// `var(--accent)`. `None` removes it, so the fallback or an inherited
+    /// value applies again.
+    SetCssVar { name: String, value: Option<String> },
+    /// Opens the browser's print dialog. Effects run after the render, so a
+    /// print layout `update` switched to is what gets printed. Most browsers
+    /// wait until the dialog is closed before running the next effect.
+    Print,
+    /// Drives the future to completion and dispatches the message it
+    /// resolves to, for async work no other effect covers. See
+    /// [`Effect::command`].
+    Command(Pin<Box<dyn Future<Output = Box<dyn AnyMessage>>>>),
+    /// Runs all effects in order. Effects that wait for something, like an
+    /// upload or a command, don't hold up the ones after them, so they run
+    /// concurrently. Use [`Effect::then`] if one needs the result of another.
+    Batch(Vec<Effect>),
+    /// See [`Effect::then`].
+    Then { first: Box<Effect>, next: OnResult },
+    /// See [`Effect::retry`].
+    Retry {
+        effect: Box<Effect>,
+        attempts: u32,
+        backoff_ms: i32,
+    },
+    /// Writes the text to the browser console with `console.log`, so `update`
+    /// can log while staying pure.
+    Log(String),
+    /// Like [`Effect::Log`], but with `console.warn`.
+    Warn(String),
+    /// Like [`Effect::Log`], but with `console.error`.
+    Error(String),
+}
+
+impl Effect {
+    /// An [`Effect::Command`] for `future`, like
+    /// `Effect::command(async move { Message::Loaded(load(id).await) })`.
+    pub fn command<M: AnyMessage>(future: impl Future<Output = M> + 'static) -> Effect {
+        Effect::Command(Box::pin(async move {
+            Box::new(future.await) as Box<dyn AnyMessage>
+        }))
+    }
+
+    /// Runs `first`, and once it dispatches its result of type `M` runs the
+    /// effect `next` returns for it instead of passing the result to
+    /// `update`, like to store a token right after fetching it. Other
+    /// messages of `first`, like its progress, reach `update` as usual.
+    ///
+    /// Unlike [`Effect::Batch`], which starts everything right away, `next`
+    /// only runs after `first` completed. If `first` never dispatches an `M`,
+    /// e.g. because it is [`Effect::SetTitle`], `next` never runs. Chains can
+    /// be nested, `next` may return another `then`.
+    ///
+    /// ```ignore
+    /// Effect::then(Effect::command(fetch_token()), |token: Result<String, String>| {
+    ///     match token {
+    ///         Ok(token) => Effect::command(async move { Message::Saved(store(token)) }),
+    ///         Err(error) => Effect::Error(error),
+    ///     }
+    /// })
+    /// ```
+    pub fn then<M: AnyMessage>(first: Effect, next: impl FnOnce(M) -> Effect + 'static) -> Effect {
+        let mut next = Some(next);
+        Effect::Then {
+            first: Box::new(first),
+            next: Box::new(move |msg| {
+                // Only the first result continues the chain.
+                if next.is_none() || !(&*msg as &dyn Any).is::<M>() {
+                    return Err(msg);
+                }
+                let msg = (msg as Box<dyn Any>).downcast::<M>().unwrap();
+                Ok(next.take().unwrap()(*msg))
+            }),
+        }
+    }
+
+    /// Runs `effect`, and if it fails for a reason that may go away by
+    /// itself, runs it again up to `attempts` more times before its callback
+    /// gets the last error. The first retry waits `backoff_ms` milliseconds,
+    /// every further one twice as long as the one before.
+    ///
+    /// Only [`Effect::Upload`] is retried, on network errors, timeouts, rate
+    /// limiting and server errors. Other effects run once as usual, also
+    /// inside of an [`Effect::Batch`].
+    pub fn retry(effect: Effect, attempts: u32, backoff_ms: i32) -> Effect {
+        Effect::Retry {
+            effect: Box::new(effect),
+            attempts,
+            backoff_ms,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Event, HtmlInputElement};
+
+use crate::{dom::Dispatch, AnyMessage, EffectError};
+
+/// A file the user picked in an `<input type="file">`.
+#[derive(Debug, Clone)]
+pub struct WebFile {
+    pub name: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// The MIME type as guessed by the browser, empty if it couldn't tell.
+    pub type_: String,
+    pub(crate) file: web_sys::File,
+}
+
+impl From<web_sys::File> for WebFile {
+    fn from(file: web_sys::File) -> Self {
+        Self {
+            name: file.name(),
+            size: file.size() as u64,
+            type_: file.type_(),
+            file,
+        }
+    }
+}
+
+/// The files currently selected in the input that fired `event`.
+pub(crate) fn selected(event: &Event) -> Vec<WebFile> {
+    let files = event
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        .and_then(|input| input.files());
+
+    match files {
+        Some(files) => (0..files.length())
+            .filter_map(|i| files.get(i))
+            .map(WebFile::from)
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Gets the contents, or why the file couldn't be read.
+pub(crate) type OnText = Box<dyn Fn(Result<String, EffectError>) -> Box<dyn AnyMessage>>;
+/// Gets the contents, or why the file couldn't be read.
+pub(crate) type OnBytes = Box<dyn Fn(Result<Vec<u8>, EffectError>) -> Box<dyn AnyMessage>>;
+
+/// Why reading a file failed, from the name and message of the
+/// `DOMException` it was rejected with.
+fn read_error(name: &str, message: String) -> EffectError {
+    match name {
+        "EncodingError" => EffectError::Decode(message),
+        "NotFoundError" => EffectError::NotFound,
+        "SecurityError" => EffectError::PermissionDenied,
+        _ => EffectError::Other(message),
+    }
+}
+
+fn reason(error: JsValue) -> EffectError {
+    match error.dyn_into::<js_sys::Error>() {
+        Ok(error) => read_error(&String::from(error.name()), error.message().into()),
+        Err(error) => EffectError::Other(
+            error
+                .as_string()
+                .unwrap_or_else(|| "unknown error".to_string()),
+        ),
+    }
+}
+
+pub(crate) fn read_text(file: WebFile, on_load: OnText, dispatch: Dispatch) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let text = JsFuture::from(file.file.text()).await.map_err(reason);
+        dispatch(on_load(text.and_then(|text| {
+            text.as_string()
+                .ok_or_else(|| EffectError::Decode(format!("`{}` isn't text", file.name)))
+        })));
+    });
+}
+
+pub(crate) fn read_bytes(file: WebFile, on_load: OnBytes, dispatch: Dispatch) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let buffer = JsFuture::from(file.file.array_buffer()).await;
+        dispatch(on_load(
+            buffer
+                .map(|buffer| js_sys::Uint8Array::new(&buffer).to_vec())
+                .map_err(reason),
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_errors_are_mapped_by_name() {
+        let error = |name| read_error(name, "reason".to_string());
+
+        assert_eq!(
+            error("EncodingError"),
+            EffectError::Decode("reason".to_string())
+        );
+        assert_eq!(error("NotFoundError"), EffectError::NotFound);
+        assert_eq!(error("SecurityError"), EffectError::PermissionDenied);
+        assert_eq!(
+            error("NotReadableError"),
+            EffectError::Other("reason".to_string())
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlTextAreaElement, KeyboardEvent};
+
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+fn focusable(container: &Element) -> Vec<HtmlElement> {
+    let nodes = match container.query_selector_all(FOCUSABLE) {
+        Ok(nodes) => nodes,
+        Err(_) => return vec![],
+    };
+
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+pub(crate) fn active_element() -> Option<Element> {
+    web_sys::window()?.document()?.active_element()
+}
+
+/// The focused element and its text selection, taken before patching.
+///
+/// Patches never recreate an element that survives the render, but moving
+/// one around, like a keyed list does, takes focus from it in most browsers.
+pub(crate) struct SavedFocus {
+    element: HtmlElement,
+    selection: Option<(u32, u32)>,
+}
+
+fn selection(element: &HtmlElement) -> Option<(u32, u32)> {
+    let (start, end) = if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        (input.selection_start(), input.selection_end())
+    } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+        (textarea.selection_start(), textarea.selection_end())
+    } else {
+        return None;
+    };
+
+    // Inputs like checkboxes throw instead of having a selection.
+    Some((start.ok()??, end.ok()??))
+}
+
+pub(crate) fn save() -> Option<SavedFocus> {
+    let element: HtmlElement = active_element()?.dyn_into().ok()?;
+    let selection = selection(&element);
+    Some(SavedFocus { element, selection })
+}
+
+impl SavedFocus {
+    /// Focuses the element again if it is still on the page but lost focus.
+    pub(crate) fn restore(self) {
+        let active = active_element();
+        let active = active.as_ref().map(|element| element.as_ref());
+
+        if !self.element.is_connected() || self.element.is_same_node(active) {
+            return;
+        }
+
+        let _ = self.element.focus();
+
+        if let Some((start, end)) = self.selection {
+            if let Some(input) = self.element.dyn_ref::<HtmlInputElement>() {
+                let _ = input.set_selection_range(start, end);
+            } else if let Some(textarea) = self.element.dyn_ref::<HtmlTextAreaElement>() {
+                let _ = textarea.set_selection_range(start, end);
+            }
+        }
+    }
+}
+
+pub(crate) fn focus_first(container: &Element) {
+    if let Some(first) = focusable(container).first() {
+        let _ = first.focus();
+    }
+}
+
+/// Keeps Tab and Shift+Tab cycling through the focusable descendants of a
+/// container. The trap is lifted when it is released or dropped.
+pub struct FocusTrap {
+    container: Element,
+    trigger: Option<HtmlElement>,
+    listener: Closure<dyn FnMut(KeyboardEvent)>,
+}
+
+/// Traps focus inside `container` and moves it to its first focusable
+/// descendant. The element that had focus before is remembered as the
+/// trigger and gets focus back on [`FocusTrap::release`].
+pub fn focus_trap(container: &Element) -> FocusTrap {
+    let trigger = active_element().and_then(|element| element.dyn_into().ok());
+    let trap = FocusTrap::new(container, trigger);
+    focus_first(container);
+    trap
+}
+
+impl FocusTrap {
+    pub(crate) fn new(container: &Element, trigger: Option<HtmlElement>) -> Self {
+        let listener = {
+            let container = container.clone();
+            Closure::wrap(Box::new(move |event: KeyboardEvent| {
+                if event.key() != "Tab" {
+                    return;
+                }
+
+                let items = focusable(&container);
+                let (first, last) = match (items.first(), items.last()) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => {
+                        event.prevent_default();
+                        return;
+                    }
+                };
+
+                let active = active_element();
+                let active = active.as_ref().map(|element| element.as_ref());
+                let inside = container.contains(active);
+
+                let wrap_to = if event.shift_key() {
+                    (!inside || first.is_same_node(active)).then_some(last)
+                } else {
+                    (!inside || last.is_same_node(active)).then_some(first)
+                };
+
+                if let Some(target) = wrap_to {
+                    event.prevent_default();
+                    let _ = target.focus();
+                }
+            }) as Box<dyn FnMut(KeyboardEvent)>)
+        };
+
+        container
+            .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+            .unwrap();
+
+        Self {
+            container: container.clone(),
+            trigger,
+            listener,
+        }
+    }
+
+    /// Moves the trap to `container`, keeping the trigger.
+    pub(crate) fn retarget(mut self, container: &Element) -> FocusTrap {
+        if self.container.is_same_node(Some(container)) {
+            return self;
+        }
+
+        FocusTrap::new(container, self.trigger.take())
+    }
+
+    /// Lifts the trap and focuses the trigger again, if it is still part of
+    /// the document.
+    pub fn release(mut self) {
+        if let Some(trigger) = self.trigger.take() {
+            if trigger.is_connected() {
+                let _ = trigger.focus();
+            }
+        }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        let _ = self
+            .container
+            .remove_event_listener_with_callback("keydown", self.listener.as_ref().unchecked_ref());
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use crate::{AnyMessage, Node};
+
+/// Something that happened to a field bound with [`FormState::bind`], to be
+/// passed back into [`FormState::apply`] from `update`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldEvent {
+    /// The user changed the value of `field`.
+    Input { field: String, value: String },
+    /// `field` lost focus.
+    Blur { field: String },
+}
+
+#[derive(Debug, Clone, Default)]
+struct Field {
+    value: String,
+    touched: bool,
+    error: Option<String>,
+}
+
+/// The values of a form's fields, which of them the user has been to and
+/// what is wrong with them. Keep it in the model, bind the inputs with
+/// [`FormState::bind`] and validate after every [`FormState::apply`]:
+///
+/// ```ignore
+/// Message::Field(event) => {
+///     self.form.apply(event);
+///     self.form.validate("email", |email| {
+///         (!email.contains('@')).then(|| "Enter an email address".to_string())
+///     });
+/// }
+/// ```
+///
+/// Errors are only shown for touched fields, ones that lost focus once, so
+/// nobody is told off for a field they haven't filled in yet. Call
+/// [`FormState::touch_all`] on submit to show every error.
+#[derive(Debug, Clone, Default)]
+pub struct FormState {
+    fields: HashMap<String, Field>,
+}
+
+impl FormState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn field(&mut self, name: &str) -> &mut Field {
+        self.fields.entry(name.to_string()).or_default()
+    }
+
+    /// The current value of `name`, empty for a field that hasn't been
+    /// changed yet.
+    pub fn value(&self, name: &str) -> &str {
+        self.fields
+            .get(name)
+            .map_or("", |field| field.value.as_str())
+    }
+
+    /// Sets the value of `name`, like to prefill the form.
+    pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
+        self.field(name).value = value.into();
+    }
+
+    /// Records what happened to a bound field.
+    pub fn apply(&mut self, event: FieldEvent) {
+        match event {
+            FieldEvent::Input { field, value } => self.field(&field).value = value,
+            FieldEvent::Blur { field } => self.field(&field).touched = true,
+        }
+    }
+
+    /// Whether `name` lost focus since the form was created or reset.
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.fields.get(name).is_some_and(|field| field.touched)
+    }
+
+    /// Marks every field that was changed, set or validated as touched, so
+    /// all errors show, like when the form is submitted.
+    pub fn touch_all(&mut self) {
+        for field in self.fields.values_mut() {
+            field.touched = true;
+        }
+    }
+
+    /// Sets the error of `name` to what `rule` returns for its value, `None`
+    /// if it is fine.
+    pub fn validate(&mut self, name: &str, rule: impl FnOnce(&str) -> Option<String>) {
+        let field = self.field(name);
+        field.error = rule(&field.value);
+    }
+
+    /// The error of `name`, whether or not it is shown yet.
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.fields.get(name)?.error.as_deref()
+    }
+
+    /// The error of `name` if it should be shown, because the field was
+    /// touched.
+    pub fn visible_error(&self, name: &str) -> Option<&str> {
+        self.error(name).filter(|_| self.is_touched(name))
+    }
+
+    /// Whether no field has an error, shown or not.
+    pub fn is_valid(&self) -> bool {
+        self.fields.values().all(|field| field.error.is_none())
+    }
+
+    /// Forgets all values, errors and which fields were touched.
+    pub fn reset(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Binds `input`, an `<input>`, `<textarea>` or `<select>`, to the field
+    /// `name`: sets its `name` and current value and sends every change and
+    /// the loss of focus through `to_message`. While an error is shown the
+    /// input gets `aria-invalid` and is described by the
+    /// [`FormState::error_text`] of the field.
+    pub fn bind<M: AnyMessage>(
+        &self,
+        name: &str,
+        input: Node,
+        to_message: impl Fn(FieldEvent) -> M + 'static,
+    ) -> Node {
+        let input = input.name(name).with_value(self.value(name));
+        let input = match self.visible_error(name) {
+            Some(_) => input
+                .with_attribute("aria-invalid", "true")
+                .with_attribute("aria-describedby", error_id(name)),
+            None => input,
+        };
+
+        let to_message = std::rc::Rc::new(to_message);
+        let field = name.to_string();
+        let on_input = {
+            let to_message = to_message.clone();
+            let field = field.clone();
+            move |value| {
+                to_message(FieldEvent::Input {
+                    field: field.clone(),
+                    value,
+                })
+            }
+        };
+        input.on_input(on_input).on_blur(move || {
+            to_message(FieldEvent::Blur {
+                field: field.clone(),
+            })
+        })
+    }
+
+    /// A `<p class="slick-field-error">` holding the error of `name` while
+    /// it is shown, and nothing otherwise. It is always rendered, so the
+    /// input can refer to it and screen readers announce the error when it
+    /// appears.
+    pub fn error_text(&self, name: &str) -> Node {
+        let text = Node::native("p")
+            .id(error_id(name))
+            .with_attribute("class", "slick-field-error")
+            .with_attribute("aria-live", "polite");
+
+        match self.visible_error(name) {
+            Some(error) => text.with_child(Node::text(error)),
+            None => text,
+        }
+    }
+}
+
+fn error_id(name: &str) -> String {
+    format!("{}-error", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mount, MockNode};
+    use crate::NodeAttributeValue;
+
+    fn email_rule(email: &str) -> Option<String> {
+        (!email.contains('@')).then(|| "Enter an email address".to_string())
+    }
+
+    /// Applies the event a mounted input produced, like `update` would.
+    fn update(form: &mut FormState, msg: Option<Box<dyn AnyMessage>>) {
+        let msg: Box<dyn std::any::Any> = msg.unwrap();
+        form.apply(*msg.downcast::<FieldEvent>().unwrap());
+        form.validate("email", email_rule);
+    }
+
+    fn input(form: &FormState) -> MockNode {
+        mount(&form.bind("email", Node::native("input"), |event| event))
+    }
+
+    fn error_text(form: &FormState) -> String {
+        mount(&form.error_text("email")).text()
+    }
+
+    #[test]
+    fn errors_of_untouched_fields_are_hidden() {
+        let mut form = FormState::new();
+        let msg = input(&form).as_element().unwrap().input("bob");
+        update(&mut form, msg);
+
+        let email = input(&form);
+        let email = email.as_element().unwrap();
+        assert_eq!(form.value("email"), "bob");
+        assert_eq!(email.value.as_deref(), Some("bob"));
+        assert_eq!(form.error("email"), Some("Enter an email address"));
+        assert_eq!(form.visible_error("email"), None);
+        assert!(!email.attributes.contains_key("aria-invalid"));
+        assert!(!email.attributes.contains_key("aria-describedby"));
+        assert_eq!(error_text(&form), "");
+        assert!(!form.is_valid());
+    }
+
+    #[test]
+    fn errors_of_touched_fields_are_shown() {
+        let mut form = FormState::new();
+        let msg = input(&form).as_element().unwrap().input("bob");
+        update(&mut form, msg);
+        let msg = input(&form).as_element().unwrap().blur();
+        update(&mut form, msg);
+
+        let email = input(&form);
+        let email = email.as_element().unwrap();
+        assert!(form.is_touched("email"));
+        assert_eq!(form.visible_error("email"), Some("Enter an email address"));
+        assert_eq!(
+            email.attributes["aria-invalid"],
+            NodeAttributeValue::from("true")
+        );
+        assert_eq!(
+            email.attributes["aria-describedby"],
+            NodeAttributeValue::from("email-error")
+        );
+        assert_eq!(error_text(&form), "Enter an email address");
+
+        update(&mut form, email.input("bob@example.com"));
+        assert!(form.is_valid());
+        assert!(!input(&form)
+            .as_element()
+            .unwrap()
+            .attributes
+            .contains_key("aria-invalid"));
+        assert_eq!(error_text(&form), "");
+    }
+
+    #[test]
+    fn touch_all_shows_every_error() {
+        let mut form = FormState::new();
+        form.validate("email", email_rule);
+        assert_eq!(error_text(&form), "");
+
+        form.touch_all();
+        assert_eq!(error_text(&form), "Enter an email address");
+
+        form.reset();
+        assert!(!form.is_touched("email"));
+        assert_eq!(form.error("email"), None);
+    }
+}
@@ -0,0 +1,50 @@
+use web_sys::{Document, Element};
+
+/// The attribute a `<meta>` tag is identified by. Open Graph uses `property`,
+/// everything else `name`.
+fn key_attribute(key: &str) -> &'static str {
+    if key.starts_with("og:") {
+        "property"
+    } else {
+        "name"
+    }
+}
+
+fn find_or_create(document: &Document, head: &Element, key: &str) -> Option<Element> {
+    let attribute = key_attribute(key);
+    let selector = format!(
+        "meta[{}=\"{}\"]",
+        attribute,
+        key.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+
+    if let Some(meta) = head.query_selector(&selector).ok().flatten() {
+        return Some(meta);
+    }
+
+    let meta = document.create_element("meta").ok()?;
+    meta.set_attribute(attribute, key).ok()?;
+    head.append_child(&meta).ok()?;
+    Some(meta)
+}
+
+pub(crate) fn set_meta(key: &str, content: &str) {
+    let document = match web_sys::window().and_then(|window| window.document()) {
+        Some(document) => document,
+        None => return,
+    };
+    let head = match document.head() {
+        Some(head) => head,
+        None => return,
+    };
+
+    if let Some(meta) = find_or_create(&document, &head, key) {
+        let _ = meta.set_attribute("content", content);
+    }
+}
+
+pub(crate) fn set_title(title: &str) {
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        document.set_title(title);
+    }
+}
@@ -0,0 +1,139 @@
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+/// The values of the [`use_state`] calls in the view of one runtime, by the
+/// position of the call.
+#[derive(Default)]
+pub(crate) struct Hooks {
+    slots: Vec<Rc<dyn Any>>,
+}
+
+/// The hooks of the view that is being rendered and the position of the next
+/// [`use_state`] call in it.
+struct Cursor {
+    slots: Vec<Rc<dyn Any>>,
+    next: usize,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Cursor>> = const { RefCell::new(None) };
+}
+
+/// Runs `view` with `hooks` as the ones [`use_state`] reads from.
+pub(crate) fn render<R>(hooks: &mut Hooks, view: impl FnOnce() -> R) -> R {
+    let cursor = Cursor {
+        slots: std::mem::take(&mut hooks.slots),
+        next: 0,
+    };
+    let outer = CURRENT.with(|current| current.replace(Some(cursor)));
+
+    let result = view();
+
+    let cursor = CURRENT
+        .with(|current| current.replace(outer))
+        .expect("the hooks of the view went missing");
+
+    #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+    if cursor.next != cursor.slots.len() && cursor.next > 0 {
+        crate::warn(&format!(
+            "`use_state` was called {} times, but {} times in an earlier render, \
+             call it the same number of times in the same order on every render",
+            cursor.next,
+            cursor.slots.len()
+        ));
+    }
+
+    hooks.slots = cursor.slots;
+    result
+}
+
+/// State that lives in the view instead of the component, for widgets that
+/// would otherwise need a struct and a message type of their own, like a
+/// toggle. Returns the current value, `init()` on the first render, and a
+/// handle to change it.
+///
+/// Values are told apart by the position of the call, so the same calls have
+/// to happen in the same order on every render:
+///
+/// - call it at the top of a view function, not in a condition, a loop or a
+///   closure that may not run,
+/// - and only while a view is rendered by the runtime, including [`Node::pure`]
+///   and [`Node::custom`] children. Anywhere else it returns `init()` every
+///   time and changes are lost.
+///
+/// A call that moves to another position, like the state of a list item when
+/// an item above it is removed, picks up the value stored there. A value of
+/// another type panics.
+///
+/// ```ignore
+/// fn toggle(label: &'static str) -> Node {
+///     let (on, set_on) = use_state(|| false);
+///     Node::native("button")
+///         .with_attribute("aria-pressed", on)
+///         .with_child(Node::text(label))
+///         .on_click_with(move || set_on.set(!on))
+/// }
+/// ```
+///
+/// [`Node::pure`]: crate::Node::pure
+/// [`Node::custom`]: crate::Node::custom
+pub fn use_state<T: Clone + 'static>(init: impl FnOnce() -> T) -> (T, SetState<T>) {
+    let slot = CURRENT.with(|current| {
+        let mut current = current.borrow_mut();
+        let cursor = current.as_mut()?;
+        let slot = cursor.slots.get(cursor.next).cloned();
+        cursor.next += 1;
+        Some(slot)
+    });
+
+    let cell = match slot {
+        Some(Some(slot)) => slot.downcast::<RefCell<T>>().unwrap_or_else(|_| {
+            panic!("`use_state` was called in a different order than in the previous render")
+        }),
+        Some(None) => {
+            let cell = Rc::new(RefCell::new(init()));
+            CURRENT.with(|current| {
+                if let Some(cursor) = current.borrow_mut().as_mut() {
+                    cursor.slots.push(cell.clone());
+                }
+            });
+            cell
+        }
+        None => Rc::new(RefCell::new(init())),
+    };
+
+    let value = cell.borrow().clone();
+    (value, SetState { cell })
+}
+
+/// Changes a value of [`use_state`].
+pub struct SetState<T> {
+    cell: Rc<RefCell<T>>,
+}
+
+impl<T> Clone for SetState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T> SetState<T> {
+    /// Replaces the value. Return the result from a handler, the runtime
+    /// re-renders when it gets it.
+    pub fn set(&self, value: T) -> LocalUpdate {
+        *self.cell.borrow_mut() = value;
+        LocalUpdate
+    }
+
+    /// Changes the value in place, see [`SetState::set`].
+    pub fn update(&self, f: impl FnOnce(&mut T)) -> LocalUpdate {
+        f(&mut self.cell.borrow_mut());
+        LocalUpdate
+    }
+}
+
+/// The message of handlers that changed a [`use_state`] value. The runtime
+/// re-renders for it without passing it to `update`.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalUpdate;
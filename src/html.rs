@@ -0,0 +1,106 @@
+use std::cell::Cell;
+
+thread_local! {
+    static WARN_UNKNOWN_ATTRIBUTES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Turns on warnings for attributes set with
+/// [`Node::with_attribute`](crate::Node::with_attribute) that no HTML
+/// element has, like `clas`. Off by default and only checked with the `dev`
+/// feature, since the list can't know every attribute. `data-*`, `aria-*`,
+/// SVG elements and custom elements, which have a `-` in their tag, are
+/// never reported.
+pub fn warn_unknown_attributes(enabled: bool) {
+    WARN_UNKNOWN_ATTRIBUTES.with(|warn| warn.set(enabled));
+}
+
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+#[rustfmt::skip]
+const ATTRIBUTES: &[&str] = &[
+    "accept", "accept-charset", "accesskey", "action", "allow", "allowfullscreen", "alt", "as",
+    "async", "autocapitalize", "autocomplete", "autofocus", "autoplay", "capture", "charset",
+    "checked", "cite", "class", "cols", "colspan", "content", "contenteditable", "controls",
+    "coords", "crossorigin", "datetime", "decoding", "default", "defer", "dir", "dirname",
+    "disabled", "download", "draggable", "enctype", "enterkeyhint", "for", "form", "formaction",
+    "formenctype", "formmethod", "formnovalidate", "formtarget", "headers", "height", "hidden",
+    "high", "href", "hreflang", "http-equiv", "id", "inert", "inputmode", "integrity", "is",
+    "itemid", "itemprop", "itemref", "itemscope", "itemtype", "kind", "label", "lang", "list",
+    "loading", "loop", "low", "max", "maxlength", "media", "method", "min", "minlength",
+    "multiple", "muted", "name", "nonce", "novalidate", "open", "optimum", "pattern", "ping",
+    "placeholder", "playsinline", "popover", "popovertarget", "popovertargetaction", "poster",
+    "preload", "property", "readonly", "referrerpolicy", "rel", "required", "reversed", "role",
+    "rows", "rowspan", "sandbox", "scope", "selected", "shape", "size", "sizes", "slot", "span",
+    "spellcheck", "src", "srcdoc", "srclang", "srcset", "start", "step", "style", "tabindex",
+    "target", "title", "translate", "type", "usemap", "value", "width", "wrap",
+];
+
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+#[rustfmt::skip]
+const SVG_TAGS: &[&str] = &[
+    "svg", "g", "path", "circle", "ellipse", "line", "polyline", "polygon", "rect", "text",
+    "tspan", "defs", "use", "symbol", "marker", "mask", "pattern", "clippath", "lineargradient",
+    "radialgradient", "stop", "foreignobject", "math",
+];
+
+/// Whether setting `key` on a `tag` element deserves a warning, see
+/// [`warn_unknown_attributes`].
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+pub(crate) fn is_unknown_attribute(tag: &str, key: &str) -> bool {
+    if !WARN_UNKNOWN_ATTRIBUTES.with(Cell::get) {
+        return false;
+    }
+
+    let tag = tag.to_ascii_lowercase();
+    let key = key.to_ascii_lowercase();
+
+    !(tag.contains('-')
+        || SVG_TAGS.contains(&tag.as_str())
+        || key.starts_with("data-")
+        || key.starts_with("aria-")
+        || key.starts_with("on")
+        || ATTRIBUTES.contains(&key.as_str()))
+}
+
+/// The warning for setting `key` on a `tag` element, if it deserves one, see
+/// [`is_unknown_attribute`].
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+pub(crate) fn unknown_attribute_warning(tag: &str, key: &str) -> Option<String> {
+    is_unknown_attribute(tag, key).then(|| {
+        format!(
+            "<{}> got the attribute `{}`, which no HTML element has, is it misspelled?",
+            tag, key
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn misspelled_attribute_warns() {
+        warn_unknown_attributes(true);
+
+        let warning = unknown_attribute_warning("div", "clas").unwrap();
+        assert!(warning.starts_with("<div> got the attribute `clas`"));
+        assert_eq!(unknown_attribute_warning("div", "class"), None);
+    }
+
+    #[test]
+    fn custom_svg_data_and_aria_attributes_do_not_warn() {
+        warn_unknown_attributes(true);
+
+        assert!(!is_unknown_attribute("my-widget", "size"));
+        assert!(!is_unknown_attribute("circle", "cx"));
+        assert!(!is_unknown_attribute("div", "data-id"));
+        assert!(!is_unknown_attribute("div", "aria-label"));
+        assert!(!is_unknown_attribute("div", "onclick"));
+    }
+
+    #[test]
+    fn nothing_warns_unless_enabled() {
+        warn_unknown_attributes(false);
+
+        assert_eq!(unknown_attribute_warning("div", "clas"), None);
+    }
+}
@@ -0,0 +1,193 @@
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{FormData, ProgressEvent, XmlHttpRequest};
+
+use crate::{dom::Dispatch, AnyMessage, EffectError, WebFile};
+
+/// How much of a request body has been sent so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Bytes sent.
+    pub loaded: f64,
+    /// Bytes to send, `None` if the browser can't tell.
+    pub total: Option<f64>,
+}
+
+impl Progress {
+    /// The sent share between `0.0` and `1.0`, if the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|total| *total > 0.0)
+            .map(|total| (self.loaded / total).min(1.0))
+    }
+}
+
+pub(crate) type OnProgress = Box<dyn Fn(Progress) -> Box<dyn AnyMessage>>;
+/// Gets the response body, or why the request failed.
+pub(crate) type OnUploaded = Box<dyn Fn(Result<String, EffectError>) -> Box<dyn AnyMessage>>;
+
+type Listener = Closure<dyn FnMut(ProgressEvent)>;
+
+fn form_data(
+    fields: &[(String, String)],
+    files: &[(String, WebFile)],
+) -> Result<FormData, JsValue> {
+    let form = FormData::new()?;
+
+    for (name, value) in fields {
+        form.append_with_str(name, value)?;
+    }
+
+    for (name, file) in files {
+        form.append_with_blob_and_filename(name, &file.file, &file.name)?;
+    }
+
+    Ok(form)
+}
+
+/// How often a failed request is sent again, see [`Effect::retry`].
+///
+/// [`Effect::retry`]: crate::Effect::retry
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Retry {
+    pub(crate) attempts: u32,
+    pub(crate) backoff_ms: i32,
+}
+
+impl Retry {
+    pub(crate) const NEVER: Retry = Retry {
+        attempts: 0,
+        backoff_ms: 0,
+    };
+}
+
+/// Failures that may go away by themselves. Anything else would fail the
+/// same way again.
+fn is_transient(error: &EffectError) -> bool {
+    match error {
+        EffectError::Network => true,
+        EffectError::Status(status) => *status == 408 || *status == 429 || *status >= 500,
+        _ => false,
+    }
+}
+
+struct Upload {
+    url: String,
+    fields: Vec<(String, String)>,
+    files: Vec<(String, WebFile)>,
+    on_progress: Option<OnProgress>,
+    on_uploaded: OnUploaded,
+}
+
+pub(crate) fn upload(
+    url: String,
+    fields: Vec<(String, String)>,
+    files: Vec<(String, WebFile)>,
+    on_progress: Option<OnProgress>,
+    on_uploaded: OnUploaded,
+    retry: Retry,
+    dispatch: Dispatch,
+) {
+    let upload = Upload {
+        url,
+        fields,
+        files,
+        on_progress,
+        on_uploaded,
+    };
+    send(Rc::new(upload), retry, dispatch);
+}
+
+/// Sends `upload` again after the backoff, which doubles every attempt.
+fn resend(upload: Rc<Upload>, retry: Retry, dispatch: Dispatch) {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+    let next = Retry {
+        attempts: retry.attempts - 1,
+        backoff_ms: retry.backoff_ms.saturating_mul(2),
+    };
+    let callback = Closure::once_into_js(move || send(upload, next, dispatch));
+    let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        callback.unchecked_ref(),
+        retry.backoff_ms,
+    );
+}
+
+/// Uses `XMLHttpRequest` because `fetch` doesn't report upload progress.
+fn send(upload: Rc<Upload>, retry: Retry, dispatch: Dispatch) {
+    let xhr = match XmlHttpRequest::new() {
+        Ok(xhr) => xhr,
+        Err(_) => {
+            let unsupported = EffectError::Unsupported("XMLHttpRequest".to_string());
+            return dispatch((upload.on_uploaded)(Err(unsupported)));
+        }
+    };
+
+    // The listeners keep each other alive until the request is done.
+    let listeners: Rc<RefCell<Vec<Listener>>> = Rc::new(RefCell::new(vec![]));
+
+    let finish = {
+        let xhr = xhr.clone();
+        let listeners = listeners.clone();
+        let upload = upload.clone();
+        let dispatch = dispatch.clone();
+        move |failed: bool| {
+            let status = xhr.status().unwrap_or_default();
+            let result = if !failed && (200..300).contains(&status) {
+                Ok(xhr.response_text().ok().flatten().unwrap_or_default())
+            } else if failed {
+                Err(EffectError::Network)
+            } else {
+                Err(EffectError::from_status(status))
+            };
+
+            listeners.borrow_mut().clear();
+            match result {
+                Err(error) if retry.attempts > 0 && is_transient(&error) => {
+                    resend(upload.clone(), retry, dispatch.clone())
+                }
+                result => dispatch((upload.on_uploaded)(result)),
+            }
+        }
+    };
+
+    let on_load = {
+        let finish = finish.clone();
+        Closure::wrap(Box::new(move |_: ProgressEvent| finish(false)) as Box<dyn FnMut(_)>)
+    };
+    let on_error =
+        Closure::wrap(Box::new(move |_: ProgressEvent| finish(true)) as Box<dyn FnMut(_)>);
+    xhr.set_onload(Some(on_load.as_ref().unchecked_ref()));
+    xhr.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    xhr.set_onabort(Some(on_error.as_ref().unchecked_ref()));
+    listeners.borrow_mut().extend([on_load, on_error]);
+
+    if let Some(xhr_upload) = upload.on_progress.as_ref().and(xhr.upload().ok()) {
+        let upload = upload.clone();
+        let dispatch = dispatch.clone();
+        let listener = Closure::wrap(Box::new(move |event: ProgressEvent| {
+            if let Some(on_progress) = &upload.on_progress {
+                dispatch(on_progress(Progress {
+                    loaded: event.loaded(),
+                    total: event.length_computable().then(|| event.total()),
+                }))
+            }
+        }) as Box<dyn FnMut(_)>);
+        xhr_upload.set_onprogress(Some(listener.as_ref().unchecked_ref()));
+        listeners.borrow_mut().push(listener);
+    }
+
+    let sent = form_data(&upload.fields, &upload.files).and_then(|form| {
+        xhr.open("POST", &upload.url)?;
+        xhr.send_with_opt_form_data(Some(&form))
+    });
+
+    if sent.is_err() {
+        listeners.borrow_mut().clear();
+        console_log!("failed to upload to `{}`", upload.url);
+        dispatch((upload.on_uploaded)(Err(EffectError::Network)));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{AnyMessage, Node};
+
+/// Renders `content` followed by an invisible sentinel. Whenever the
+/// sentinel comes into view, be it because the user scrolled near the end or
+/// because the content doesn't fill the page yet, `on_load_more` is
+/// dispatched, once until it leaves the view again.
+///
+/// Pass `enabled: false` while a page is loading and once there is nothing
+/// left to load. Enabling it again with the sentinel still in view, because
+/// the page that was loaded didn't fill the screen, asks for the next one.
+pub fn infinite_scroll<M: AnyMessage>(
+    content: Node,
+    enabled: bool,
+    on_load_more: impl Fn() -> M + 'static,
+) -> Node {
+    let sentinel = Node::native("div")
+        .with_attribute("aria-hidden", "true")
+        .with_attribute("style", "height: 1px");
+
+    Node::native("div")
+        .with_child(content)
+        .with_child(if enabled {
+            sentinel.on_visible(on_load_more)
+        } else {
+            sentinel
+        })
+}
@@ -0,0 +1,18 @@
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// Parses the JSON in the attribute `key` of `element`, as written by
+/// [`Node::with_json`](crate::Node::with_json). `None` if the attribute is
+/// missing or isn't valid JSON.
+pub fn read_json_attribute(element: &Element, key: &str) -> Option<JsValue> {
+    let json = element.get_attribute(key)?;
+    js_sys::JSON::parse(&json).ok()
+}
+
+/// Reads the attribute `key` of `element` back into the value that was
+/// written with [`Node::with_json_of`](crate::Node::with_json_of). `None`
+/// if the attribute is missing or doesn't hold a `T`.
+#[cfg(feature = "serde")]
+pub fn read_json<T: serde::de::DeserializeOwned>(element: &Element, key: &str) -> Option<T> {
+    serde_json::from_str(&element.get_attribute(key)?).ok()
+}
@@ -0,0 +1,131 @@
+use std::{any::TypeId, cell::RefCell, collections::HashMap, future::Future, rc::Rc};
+
+use crate::{runtime, Component, Effect, Node};
+
+enum Load {
+    Pending,
+    Done(Rc<dyn Component<Message = ()>>),
+}
+
+/// A loaded component, shared between every place that renders it.
+struct Loaded(Rc<dyn Component<Message = ()>>);
+
+impl Component for Loaded {
+    type Message = ();
+
+    fn view(&self) -> Node {
+        self.0.view()
+    }
+
+    fn update(&mut self, _: ()) -> Option<Effect> {
+        None
+    }
+
+    fn ready(&self) -> bool {
+        self.0.ready()
+    }
+
+    fn css(&self) -> &str {
+        self.0.css()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+}
+
+thread_local! {
+    static LOADS: RefCell<HashMap<TypeId, Load>> = RefCell::new(HashMap::new());
+}
+
+/// Renders `placeholder` until the future returned by `load` resolves, then
+/// renders the loaded component from then on, embedded like with
+/// [`Node::custom`].
+///
+/// `load` is only called the first time a component of type `C` is rendered
+/// and loads are shared between all places rendering `C`. Once the future
+/// resolves every mounted app re-renders. The future can be anything, e.g.
+/// fetching the data or the wasm module the component needs.
+pub fn lazy<C, F>(placeholder: Node, load: impl FnOnce() -> F) -> Node
+where
+    C: Component<Message = ()> + 'static,
+    F: Future<Output = C> + 'static,
+{
+    let id = TypeId::of::<C>();
+
+    let loaded = LOADS.with(|loads| {
+        let mut loads = loads.borrow_mut();
+
+        match loads.get(&id) {
+            Some(Load::Done(component)) => Some(component.clone()),
+            Some(Load::Pending) => None,
+            None => {
+                loads.insert(id, Load::Pending);
+
+                let future = load();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let component = future.await;
+                    LOADS.with(|loads| {
+                        loads
+                            .borrow_mut()
+                            .insert(id, Load::Done(Rc::new(component)));
+                    });
+                    runtime::request_render();
+                });
+
+                None
+            }
+        }
+    });
+
+    // Rendered outside of the borrow, the component may be lazy itself.
+    match loaded {
+        Some(component) => Node::custom(Box::new(Loaded(component))),
+        None => placeholder,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NodeKind;
+
+    use super::*;
+
+    struct Settings;
+
+    impl Component for Settings {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            Node::native("form")
+        }
+
+        fn update(&mut self, _: ()) -> Option<Effect> {
+            None
+        }
+
+        fn css(&self) -> &str {
+            "form { display: grid; }"
+        }
+    }
+
+    #[test]
+    fn loaded_component_is_embedded() {
+        let id = TypeId::of::<Settings>();
+        LOADS.with(|loads| {
+            let loaded = Load::Done(Rc::new(Settings));
+            loads.borrow_mut().insert(id, loaded);
+        });
+
+        let node = lazy(Node::text("Loading"), || async { Settings });
+
+        match &node.kind {
+            NodeKind::Custom { component, .. } => {
+                assert!(component.name().ends_with("Settings"));
+                assert_eq!(component.css(), "form { display: grid; }");
+            }
+            kind => panic!("expected a component, got {:?}", kind),
+        }
+        assert_eq!(node.to_html(), Settings.view().to_html());
+    }
+}
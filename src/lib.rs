@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    // Use `js_namespace` here to bind `console.log(..)` instead of just
+    // `log(..)`
+    #[wasm_bindgen(js_namespace = console)]
+    fn log(s: &str);
+    #[wasm_bindgen(js_namespace = console)]
+    fn warn(s: &str);
+    #[wasm_bindgen(js_namespace = console)]
+    fn error(s: &str);
+}
+
+macro_rules! console_log {
+    ($($t:tt)*) => (crate::log(&format_args!($($t)*).to_string()))
+}
+
+pub mod aria;
+mod clipboard;
+mod component;
+mod connectivity;
+pub mod css;
+mod custom_element;
+pub mod diff;
+mod dom;
+pub mod drag;
+mod effect;
+mod file;
+mod form;
+pub mod focus;
+mod head;
+mod hooks;
+mod html;
+pub mod http;
+mod infinite_scroll;
+mod json;
+mod lazy;
+mod measure;
+mod media;
+mod node;
+pub mod placeholder;
+pub mod router;
+mod roving;
+mod runtime;
+mod sanitize;
+mod select;
+mod subscription;
+mod suspense;
+mod tabs;
+pub mod testing;
+mod unload;
+mod virtual_list;
+
+pub use component::Component;
+pub use connectivity::is_online;
+pub use custom_element::{define_element, define_shadow_element};
+pub use effect::{Effect, EffectError};
+pub use file::WebFile;
+pub use form::{FieldEvent, FormState};
+pub use hooks::{use_state, LocalUpdate, SetState};
+pub use html::warn_unknown_attributes;
+pub use infinite_scroll::infinite_scroll;
+#[cfg(feature = "serde")]
+pub use json::read_json;
+pub use json::read_json_attribute;
+pub use lazy::lazy;
+pub use measure::Rect;
+pub use node::{
+    AnimationHandler, AnyMessage, Attributes, ClickHandler, DragHandler, FilesHandler,
+    InputHandler, KeyBinding, Modifiers, MouseDragHandler, Node, NodeAttributeValue, NodeKind,
+    PositionHandler, RenderOptions, ResizeHandler, ScrollHandler, VisibilityHandler,
+    VisibleHandler, WheelHandler, WheelInfo,
+};
+pub use roving::roving;
+pub use runtime::{
+    mount_into, request_render, run, run_in_shadow, run_with_config, BatchMode, Dispatcher,
+    RuntimeConfig,
+};
+pub use select::select;
+pub use subscription::Subscription;
+pub use suspense::Suspense;
+pub use tabs::tabs;
+pub use virtual_list::virtual_list;
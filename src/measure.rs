@@ -0,0 +1,35 @@
+use crate::{dom, dom::Dispatch, AnyMessage};
+
+/// The position and size of an element relative to the viewport, in CSS
+/// pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+pub(crate) type OnRect = Box<dyn Fn(Rect) -> Box<dyn AnyMessage>>;
+
+pub(crate) fn measure(root: &web_sys::Node, selector: &str, on_rect: OnRect, dispatch: Dispatch) {
+    let element = match dom::query_selector(root, selector) {
+        Some(element) => element,
+        None => {
+            #[cfg(feature = "dev")]
+            crate::warn(&format!(
+                "no element matches `{}`, ignoring the measure effect",
+                selector
+            ));
+            return;
+        }
+    };
+
+    let rect = element.get_bounding_client_rect();
+    dispatch(on_rect(Rect {
+        x: rect.x(),
+        y: rect.y(),
+        width: rect.width(),
+        height: rect.height(),
+    }));
+}
@@ -0,0 +1,45 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlMediaElement;
+
+use crate::dom;
+
+/// The `<audio>` or `<video>` element matching `selector` inside of `root`.
+fn find(root: &web_sys::Node, selector: &str) -> Option<HtmlMediaElement> {
+    let media = dom::query_selector(root, selector)
+        .and_then(|element| element.dyn_into::<HtmlMediaElement>().ok());
+
+    #[cfg(feature = "dev")]
+    if media.is_none() {
+        crate::warn(&format!(
+            "no <audio> or <video> element matches `{}`, ignoring the media effect",
+            selector
+        ));
+    }
+
+    media
+}
+
+pub(crate) fn play(root: &web_sys::Node, selector: &str) {
+    if let Some(media) = find(root, selector) {
+        // Rejects when the browser blocks autoplay or can't play the source,
+        // awaited so that isn't reported as an unhandled rejection.
+        if let Ok(promise) = media.play() {
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = JsFuture::from(promise).await;
+            });
+        }
+    }
+}
+
+pub(crate) fn pause(root: &web_sys::Node, selector: &str) {
+    if let Some(media) = find(root, selector) {
+        let _ = media.pause();
+    }
+}
+
+pub(crate) fn seek(root: &web_sys::Node, selector: &str, seconds: f64) {
+    if let Some(media) = find(root, selector) {
+        media.set_current_time(seconds);
+    }
+}
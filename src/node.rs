@@ -0,0 +1,2290 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+
+use crate::{component::Pure, css, drag::MouseDrag, Component, WebFile};
+
+/// Escapes the characters that would otherwise be read as markup.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    escape_into(&mut escaped, value);
+    escaped
+}
+
+/// Like [`escape`], but appends to `out`.
+fn escape_into(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Values compare equal only if they are the same variant, `Number(1)` is
+/// not equal to `String("1")`. The diff compares [`as_text`](Self::as_text)
+/// instead, since all the DOM sees is the text.
+///
+/// There is deliberately no float variant. `f64` is neither `Eq` nor `Hash`,
+/// which the hash of a [`Node`] needs, and `NaN` never equals itself, so
+/// [`Node::same_structure`] would tell two identical trees apart. Format
+/// fractional values into a string instead, like `format!("{:.2}", opacity)`,
+/// which also decides how many digits end up in the DOM.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NodeAttributeValue {
+    String(String),
+    Number(i32),
+    Boolean(bool),
+}
+
+impl NodeAttributeValue {
+    pub fn as_text(&self) -> String {
+        match self {
+            NodeAttributeValue::String(x) => x.clone(),
+            NodeAttributeValue::Number(x) => x.to_string(),
+            NodeAttributeValue::Boolean(x) => x.to_string(),
+        }
+    }
+
+    /// Whether the attribute is rendered and set in the DOM at all.
+    /// `Boolean(false)` isn't, since browsers treat a boolean attribute with
+    /// any value, even `"false"`, as true.
+    pub fn is_present(&self) -> bool {
+        *self != NodeAttributeValue::Boolean(false)
+    }
+}
+
+impl Display for NodeAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                NodeAttributeValue::String(x) => format!("\"{}\"", escape(x)),
+                NodeAttributeValue::Number(x) => x.to_string(),
+                NodeAttributeValue::Boolean(x) => x.to_string(),
+            }
+        )
+    }
+}
+
+impl From<String> for NodeAttributeValue {
+    fn from(x: String) -> Self {
+        NodeAttributeValue::String(x)
+    }
+}
+
+impl<'a> From<&'a str> for NodeAttributeValue {
+    fn from(x: &'a str) -> Self {
+        NodeAttributeValue::String(x.to_string())
+    }
+}
+
+impl From<i32> for NodeAttributeValue {
+    fn from(x: i32) -> Self {
+        NodeAttributeValue::Number(x)
+    }
+}
+
+impl From<bool> for NodeAttributeValue {
+    fn from(x: bool) -> Self {
+        NodeAttributeValue::Boolean(x)
+    }
+}
+
+impl From<u32> for NodeAttributeValue {
+    fn from(x: u32) -> Self {
+        NodeAttributeValue::Number(x as i32)
+    }
+}
+
+pub trait AnyMessage: std::any::Any + std::fmt::Debug {}
+
+impl<T: std::any::Any + std::fmt::Debug> AnyMessage for T {}
+
+pub enum NodeKind {
+    Native { tag: String },
+    Text(String),
+    Custom {
+        component: Box<dyn Component<Message = ()>>,
+        /// What the component rendered, with the attributes and children
+        /// added to the node.
+        rendered: Box<Node>,
+        /// How many children were added to the node. They are the last ones
+        /// of the root of `rendered`.
+        added: usize,
+    },
+}
+
+impl std::fmt::Debug for NodeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeKind::Native { tag } => f.debug_struct("Native").field("tag", tag).finish(),
+            NodeKind::Text(x) => f.debug_tuple("Text").field(x).finish(),
+            NodeKind::Custom {
+                component,
+                rendered,
+                ..
+            } => f
+                .debug_struct("Custom")
+                .field("name", &component.name())
+                .field("rendered", rendered)
+                .finish(),
+        }
+    }
+}
+
+/// Attributes are kept in insertion order so rendering is deterministic.
+pub type Attributes = IndexMap<&'static str, NodeAttributeValue>;
+
+pub type ClickHandler = Rc<dyn Fn() -> Box<dyn AnyMessage>>;
+pub type InputHandler = Rc<dyn Fn(String) -> Box<dyn AnyMessage>>;
+pub type ScrollHandler = Rc<dyn Fn(i32) -> Box<dyn AnyMessage>>;
+pub type VisibleHandler = Rc<dyn Fn() -> Box<dyn AnyMessage>>;
+pub type VisibilityHandler = Rc<dyn Fn(bool) -> Box<dyn AnyMessage>>;
+/// Called with the index of the item being dragged, see
+/// [`Node::on_drag_start`].
+pub type DragHandler = Rc<dyn Fn(usize) -> Box<dyn AnyMessage>>;
+pub type FilesHandler = Rc<dyn Fn(Vec<WebFile>) -> Box<dyn AnyMessage>>;
+pub type AnimationHandler = Rc<dyn Fn(String) -> Box<dyn AnyMessage>>;
+
+/// What [`Node::on_wheel`] handlers get to know about a wheel event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WheelInfo {
+    pub delta_x: f64,
+    pub delta_y: f64,
+    pub ctrl_key: bool,
+    pub shift_key: bool,
+    pub alt_key: bool,
+    pub meta_key: bool,
+}
+
+pub type WheelHandler = Rc<dyn Fn(WheelInfo) -> Box<dyn AnyMessage>>;
+
+/// The modifier keys that have to be held for a [`Node::on_key`] binding,
+/// combined with `|`, as in `Modifiers::CTRL | Modifiers::SHIFT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers {
+        ctrl: false,
+        shift: false,
+        alt: false,
+        meta: false,
+    };
+    pub const CTRL: Modifiers = Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    };
+    pub const SHIFT: Modifiers = Modifiers {
+        shift: true,
+        ..Modifiers::NONE
+    };
+    pub const ALT: Modifiers = Modifiers {
+        alt: true,
+        ..Modifiers::NONE
+    };
+    pub const META: Modifiers = Modifiers {
+        meta: true,
+        ..Modifiers::NONE
+    };
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, other: Modifiers) -> Modifiers {
+        Modifiers {
+            ctrl: self.ctrl || other.ctrl,
+            shift: self.shift || other.shift,
+            alt: self.alt || other.alt,
+            meta: self.meta || other.meta,
+        }
+    }
+}
+
+/// A key, as in `KeyboardEvent.key`, the modifiers held with it and the
+/// handler called when both match.
+pub type KeyBinding = (String, Modifiers, ClickHandler);
+
+/// Receives the pointer position relative to the viewport, in pixels.
+pub type PositionHandler = Rc<dyn Fn((i32, i32)) -> Box<dyn AnyMessage>>;
+pub type MouseDragHandler = Rc<dyn Fn(MouseDrag) -> Box<dyn AnyMessage>>;
+pub type ResizeHandler = Rc<dyn Fn(f64, f64) -> Box<dyn AnyMessage>>;
+
+pub struct Node {
+    pub kind: NodeKind,
+    pub children: Vec<Node>,
+    /// See [`Node::with_key`].
+    pub key: Option<String>,
+    pub on_click: Option<ClickHandler>,
+    pub on_double_click: Option<ClickHandler>,
+    pub on_context_menu: Option<PositionHandler>,
+    /// The handler set by [`Node::on_wheel`] and whether the default is
+    /// prevented.
+    pub on_wheel: Option<(WheelHandler, bool)>,
+    pub on_key: Vec<KeyBinding>,
+    pub on_input: Option<InputHandler>,
+    pub on_blur: Option<ClickHandler>,
+    /// The handlers set by [`Node::on_paste`] and [`Node::on_copy`] and
+    /// whether the default is prevented.
+    pub on_paste: Option<(InputHandler, bool)>,
+    pub on_copy: Option<(ClickHandler, bool)>,
+    pub on_scroll: Option<ScrollHandler>,
+    pub on_visible: Option<VisibleHandler>,
+    /// The threshold and handler set by [`Node::on_intersect`].
+    pub on_intersect: Option<(f64, VisibilityHandler)>,
+    pub on_drag_start: Option<(usize, DragHandler)>,
+    pub on_drag_over: Option<DragHandler>,
+    pub on_drop: Option<DragHandler>,
+    pub on_mouse_drag: Option<MouseDragHandler>,
+    pub on_files: Option<FilesHandler>,
+    pub on_transition_end: Option<AnimationHandler>,
+    pub on_animation_end: Option<AnimationHandler>,
+    pub on_resize: Option<ResizeHandler>,
+    pub attributes: Attributes,
+    /// See [`Node::with_focus_trap`].
+    pub focus_trap: bool,
+    /// See [`Node::with_roving_focus`].
+    pub roving_focus: bool,
+    /// See [`Node::with_value`].
+    pub value: Option<String>,
+}
+
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("kind", &self.kind)
+            .field("children", &self.children)
+            .field("key", &self.key)
+            .field("on_click", &self.on_click.is_some())
+            .field("on_double_click", &self.on_double_click.is_some())
+            .field("on_context_menu", &self.on_context_menu.is_some())
+            .field(
+                "on_wheel",
+                &self.on_wheel.as_ref().map(|(_, prevent_default)| prevent_default),
+            )
+            .field(
+                "on_key",
+                &self.on_key.iter().map(|(key, modifiers, _)| (key, modifiers)).collect::<Vec<_>>(),
+            )
+            .field("on_input", &self.on_input.is_some())
+            .field("on_blur", &self.on_blur.is_some())
+            .field(
+                "on_paste",
+                &self.on_paste.as_ref().map(|(_, prevent_default)| prevent_default),
+            )
+            .field(
+                "on_copy",
+                &self.on_copy.as_ref().map(|(_, prevent_default)| prevent_default),
+            )
+            .field("on_scroll", &self.on_scroll.is_some())
+            .field("on_visible", &self.on_visible.is_some())
+            .field("on_intersect", &self.on_intersect.as_ref().map(|(threshold, _)| threshold))
+            .field("on_drag_start", &self.on_drag_start.is_some())
+            .field("on_drag_over", &self.on_drag_over.is_some())
+            .field("on_drop", &self.on_drop.is_some())
+            .field("on_mouse_drag", &self.on_mouse_drag.is_some())
+            .field("on_files", &self.on_files.is_some())
+            .field("on_transition_end", &self.on_transition_end.is_some())
+            .field("on_animation_end", &self.on_animation_end.is_some())
+            .field("on_resize", &self.on_resize.is_some())
+            .field("attributes", &self.attributes)
+            .field("focus_trap", &self.focus_trap)
+            .field("roving_focus", &self.roving_focus)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// Constructor helpers
+impl Node {
+    fn new(kind: NodeKind) -> Self {
+        Self {
+            kind,
+            children: vec![],
+            key: None,
+            on_click: None,
+            on_double_click: None,
+            on_context_menu: None,
+            on_wheel: None,
+            on_key: Vec::new(),
+            on_input: None,
+            on_blur: None,
+            on_paste: None,
+            on_copy: None,
+            on_scroll: None,
+            on_visible: None,
+            on_intersect: None,
+            on_drag_start: None,
+            on_drag_over: None,
+            on_drop: None,
+            on_mouse_drag: None,
+            on_files: None,
+            on_transition_end: None,
+            on_animation_end: None,
+            on_resize: None,
+            attributes: Attributes::new(),
+            focus_trap: false,
+            roving_focus: false,
+            value: None,
+        }
+    }
+
+    pub fn native(tag: impl Into<String>) -> Self {
+        Self::new(NodeKind::Native { tag: tag.into() })
+    }
+
+    pub fn text(value: impl Into<String>) -> Self {
+        Self::new(NodeKind::Text(value.into()))
+    }
+
+    /// Like [`Node::text`], but takes anything that implements `Display`, so
+    /// numbers don't need a `.to_string()` first.
+    pub fn text_of(value: impl Display) -> Self {
+        Self::text(value.to_string())
+    }
+
+    /// Embeds a component. It is rendered right away, as part of the view it
+    /// is placed in, so however deeply components are nested, a render of
+    /// the runtime diffs and patches the whole tree once.
+    ///
+    /// Attributes and children added to the node afterwards go to the root
+    /// element the component rendered, a `class` or `style` is appended to
+    /// the one it has.
+    ///
+    /// The component renders when the node is created and again whenever
+    /// the runtime renders, see [`Node::refresh`], so it shows its current
+    /// state even if it changed after the node was created.
+    pub fn custom(value: Box<dyn Component<Message = ()>>) -> Self {
+        Self::new(NodeKind::Custom {
+            rendered: Box::new(value.view()),
+            component: value,
+            added: 0,
+        })
+    }
+
+    /// Embeds a component that is nothing but a view function, like
+    /// `Node::pure(avatar, url)` for `fn avatar(url: &str) -> Node`, and shows
+    /// up under the name of `view` in debug output. Components with state
+    /// still implement [`Component`] and go through [`Node::custom`].
+    pub fn pure<P, F>(view: F, props: P) -> Self
+    where
+        P: Clone + 'static,
+        F: Fn(P) -> Node + 'static,
+    {
+        Self::custom(Box::new(Pure { view, props }))
+    }
+}
+
+/// Builder methods
+impl Node {
+    pub fn with_child(mut self, child: Node) -> Self {
+        self.host().children.push(child);
+        self.count_added(|added| added + 1);
+        self
+    }
+
+    /// Appends a child rendered by `render` for every item, keyed by `key`,
+    /// see [`Node::with_key`]. Keys have to be unique among the items, like
+    /// an id, not the position in the list.
+    pub fn with_children_keyed<T, K: Into<String>>(
+        self,
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> K,
+        render: impl Fn(T) -> Node,
+    ) -> Self {
+        items.into_iter().fold(self, |node, item| {
+            let key = key(&item);
+            node.with_child(render(item).with_key(key))
+        })
+    }
+
+    /// See [`Node::insert_child`].
+    pub fn with_child_at(mut self, index: usize, child: Node) -> Self {
+        self.insert_child(index, child);
+        self
+    }
+
+    /// Identifies the node among its siblings. When every child of an
+    /// element has a unique key, re-renders match children up by key instead
+    /// of by position, so reordering a list moves the existing DOM nodes
+    /// (keeping focus, scroll positions and playing media) instead of
+    /// rebuilding them.
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn with_attribute(
+        mut self,
+        key: &'static str,
+        value: impl Into<NodeAttributeValue>,
+    ) -> Self {
+        #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+        if let NodeKind::Native { tag } = &self.kind {
+            if let Some(warning) = crate::html::unknown_attribute_warning(tag, key) {
+                crate::warn(&warning);
+            }
+        }
+
+        if let NodeKind::Custom { .. } = self.kind {
+            let mut attributes = Attributes::new();
+            attributes.insert(key, value.into());
+            self.merge_attributes(&attributes);
+        } else {
+            self.attributes.insert(key, value.into());
+        }
+        self
+    }
+
+    /// Sets the attribute `key`, like `data-props`, to serialized JSON, e.g.
+    /// from `serde_json::to_string`, for the client to pick up again with
+    /// [`read_json_attribute`](crate::read_json_attribute). Quotes are
+    /// escaped by [`Node::to_html`], so any JSON keeps the markup valid.
+    pub fn with_json(self, key: &'static str, json: impl Into<String>) -> Self {
+        self.with_attribute(key, json.into())
+    }
+
+    /// Like [`Node::with_json`], but serializes `value` with `serde_json`,
+    /// for the client to read back with [`read_json`](crate::read_json).
+    ///
+    /// # Panics
+    ///
+    /// If `value` can't be serialized, like a map with keys that aren't
+    /// strings.
+    #[cfg(feature = "serde")]
+    pub fn with_json_of(self, key: &'static str, value: &impl serde::Serialize) -> Self {
+        let json = serde_json::to_string(value)
+            .unwrap_or_else(|error| panic!("can't serialize `{}`: {}", key, error));
+        self.with_json(key, json)
+    }
+
+    /// Appends the content of `html`, like a user's comment with formatting,
+    /// as child nodes, without passing it through `innerHTML`. Only a small
+    /// allowlist of formatting tags and attributes is kept: `<script>`,
+    /// `<style>`, `<iframe>` and the like are dropped with their content,
+    /// other unknown tags are unwrapped, event handler attributes like
+    /// `onclick` and `style` are removed, and so are `href` and `src` with a
+    /// `javascript:`, `vbscript:` or `data:` URL.
+    ///
+    /// This is a best-effort defense for small amounts of markup, not a full
+    /// sanitizer. It doesn't parse HTML the way browsers do, so prefer a
+    /// vetted sanitizer on the server where the markup comes from untrusted
+    /// users.
+    pub fn sanitized_html(mut self, html: &str) -> Self {
+        let children = crate::sanitize::sanitize(html);
+        self.count_added(|added| added + children.len());
+        self.host().children.extend(children);
+        self
+    }
+
+    /// Sets every attribute of `attributes`, like a group that belongs
+    /// together. Later values win over earlier ones for the same key.
+    pub fn with_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = (&'static str, NodeAttributeValue)>,
+    ) -> Self {
+        for (key, value) in attributes {
+            self = self.with_attribute(key, value);
+        }
+        self
+    }
+
+    /// Like [`Node::with_attributes`], but only if `condition` holds, e.g. to
+    /// add `role` and `tabindex` only while an element is interactive.
+    pub fn with_attributes_if(
+        self,
+        condition: bool,
+        attributes: impl IntoIterator<Item = (&'static str, NodeAttributeValue)>,
+    ) -> Self {
+        if condition {
+            self.with_attributes(attributes)
+        } else {
+            self
+        }
+    }
+
+    fn with_flag(mut self, key: &'static str, on: bool) -> Self {
+        // Kept for when the component renders again, false ones aren't
+        // rendered anyway.
+        if let NodeKind::Custom { .. } = self.kind {
+            return self.with_attribute(key, on);
+        }
+
+        let attributes = &mut self.host().attributes;
+        if on {
+            attributes.insert(key, NodeAttributeValue::Boolean(true));
+        } else {
+            attributes.shift_remove(key);
+        }
+        self
+    }
+
+    /// Sets the `disabled` attribute if `disabled` holds and removes it
+    /// otherwise, since the browser treats `disabled="false"` as disabled
+    /// too.
+    pub fn disabled(self, disabled: bool) -> Self {
+        self.with_flag("disabled", disabled)
+    }
+
+    /// Like [`Node::disabled`], for `checked`. Only decides whether a
+    /// checkbox starts out checked, once the user toggled it the browser
+    /// keeps their choice.
+    pub fn checked(self, checked: bool) -> Self {
+        self.with_flag("checked", checked)
+    }
+
+    /// Like [`Node::disabled`], for `readonly`.
+    pub fn readonly(self, readonly: bool) -> Self {
+        self.with_flag("readonly", readonly)
+    }
+
+    /// Like [`Node::disabled`], for `required`.
+    pub fn required(self, required: bool) -> Self {
+        self.with_flag("required", required)
+    }
+
+    /// Like [`Node::disabled`], for `hidden`.
+    pub fn hidden(self, hidden: bool) -> Self {
+        self.with_flag("hidden", hidden)
+    }
+
+    /// Disables an element that acts as a button without being one, like a
+    /// `<div role="button">`, which `disabled` has no effect on. Sets
+    /// `aria-disabled="true"`, takes the element out of the tab order by
+    /// removing `tabindex` and drops the click handlers, so clicks dispatch
+    /// nothing. Handlers added after this call are kept, so call it last.
+    /// Without `disabled` only a previous `aria-disabled` is removed.
+    pub fn aria_disabled(mut self, disabled: bool) -> Self {
+        let attributes = &mut self.host().attributes;
+        if !disabled {
+            attributes.shift_remove("aria-disabled");
+            return self;
+        }
+        attributes.insert("aria-disabled", "true".into());
+        attributes.shift_remove("tabindex");
+        if let Some(on_click) = self.on_click.take() {
+            // Keys that activate the element, see `Node::button_role`.
+            self.on_key.retain(|(_, _, f)| !Rc::ptr_eq(f, &on_click));
+        }
+        self.on_double_click = None;
+        self
+    }
+
+    /// Makes a generic element, like a `<div>`, act as a button: sets
+    /// `role="button"`, puts it into the tab order and has Enter and Space
+    /// run the click handler, like they do for a `<button>`. Only the click
+    /// handler set before this call is wired to the keys, so call it after
+    /// [`Node::on_click`].
+    pub fn button_role(self) -> Self {
+        self.with_attribute("role", "button")
+            .with_attribute("tabindex", 0)
+            .activate_with(&["Enter", " "])
+    }
+
+    /// Like [`Node::button_role`], for a checkbox showing `checked`, which
+    /// sets `role="checkbox"` and `aria-checked`. Only Space runs the click
+    /// handler, which is expected to toggle the state.
+    pub fn checkbox_role(self, checked: bool) -> Self {
+        self.with_attribute("role", "checkbox")
+            .with_attribute("aria-checked", checked.to_string())
+            .with_attribute("tabindex", 0)
+            .activate_with(&[" "])
+    }
+
+    /// Like [`Node::button_role`], for a tab in a `role="tablist"`, which
+    /// sets `role="tab"` and `aria-selected`. Only the `selected` tab is in
+    /// the tab order, moving between tabs is up to the arrow keys, see
+    /// [`roving`](crate::roving).
+    pub fn tab_role(self, selected: bool) -> Self {
+        self.with_attribute("role", "tab")
+            .with_attribute("aria-selected", selected.to_string())
+            .with_attribute("tabindex", if selected { 0 } else { -1 })
+            .activate_with(&["Enter", " "])
+    }
+
+    /// Sets `role="dialog"` and `aria-modal="true"`, and makes the element
+    /// focusable from code but not by tabbing, so focus can be moved into a
+    /// dialog that starts without a focusable child. Label it with
+    /// `aria-labelledby` or `aria-label`.
+    pub fn dialog_role(self) -> Self {
+        self.with_attribute("role", "dialog")
+            .with_attribute("aria-modal", "true")
+            .with_attribute("tabindex", -1)
+    }
+
+    fn activate_with(mut self, keys: &[&str]) -> Self {
+        if let Some(on_click) = &self.on_click {
+            for key in keys {
+                self.on_key.push((key.to_string(), Modifiers::NONE, on_click.clone()));
+            }
+        }
+        self
+    }
+
+    /// Sets the `href` attribute.
+    pub fn href(self, href: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("href", href)
+    }
+
+    /// Sets the `src` attribute.
+    pub fn src(self, src: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("src", src)
+    }
+
+    /// Sets the `alt` attribute.
+    pub fn alt(self, alt: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("alt", alt)
+    }
+
+    /// Sets the `title` attribute.
+    pub fn title(self, title: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("title", title)
+    }
+
+    /// Sets the `placeholder` attribute.
+    pub fn placeholder(self, placeholder: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("placeholder", placeholder)
+    }
+
+    /// Sets the `id` attribute.
+    pub fn id(self, id: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("id", id)
+    }
+
+    /// Sets the `name` attribute.
+    pub fn name(self, name: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("name", name)
+    }
+
+    /// Sets the `type` attribute.
+    pub fn type_(self, type_: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("type", type_)
+    }
+
+    /// Sets the `value` attribute. For inputs that only sets the initial
+    /// value, see [`Node::with_value`] to control the current one.
+    pub fn value(self, value: impl Into<NodeAttributeValue>) -> Self {
+        self.with_attribute("value", value)
+    }
+
+    /// Sets a numeric attribute, clamping `value` into `min..=max`, e.g. to
+    /// keep a `colspan` from going negative. With the `dev` feature values
+    /// that had to be clamped are reported in the console.
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn with_number_clamped(self, key: &'static str, value: i32, min: i32, max: i32) -> Self {
+        let clamped = value.clamp(min, max);
+
+        #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+        if clamped != value {
+            crate::warn(&format!(
+                "`{}` was set to {}, which is outside of {}..={}, using {} instead",
+                key, value, min, max, clamped
+            ));
+        }
+
+        self.with_attribute(key, clamped)
+    }
+
+    /// Appends the declaration `property: value` to the `style` attribute.
+    /// Lengths can be written with the helpers in [`css`](crate::css), e.g.
+    /// `.with_style("width", px(120))`.
+    pub fn with_style(mut self, property: &str, value: impl Display) -> Self {
+        let mut style = Attributes::new();
+        style.insert("style", format!("{}: {}", property, value).into());
+        self.merge_attributes(&style);
+        self
+    }
+
+    /// Dispatches `msg` on every click. The node keeps `msg` and hands out a
+    /// clone per click, hence the `Clone` bound. Use [`Node::on_click_with`]
+    /// for messages that can't be cloned.
+    pub fn on_click<M: AnyMessage + Clone>(self, msg: M) -> Self {
+        self.on_click_with(move || msg.clone())
+    }
+
+    /// `f` is called on every click to produce the message that gets
+    /// dispatched to the component.
+    pub fn on_click_with<M: AnyMessage>(mut self, f: impl Fn() -> M + 'static) -> Self {
+        self.on_click = Some(Rc::new(move || Box::new(f())));
+        self
+    }
+
+    /// `f` is called on every double click. A double click is preceded by
+    /// two regular clicks, so an `on_click` on the same element fires for
+    /// both of them as well.
+    pub fn on_double_click<M: AnyMessage>(mut self, f: impl Fn() -> M + 'static) -> Self {
+        self.on_double_click = Some(Rc::new(move || Box::new(f())));
+        self
+    }
+
+    /// `f` receives the `(x, y)` position of the pointer relative to the
+    /// viewport when the context menu is requested, usually by a right
+    /// click. The browser's own menu is suppressed.
+    pub fn on_context_menu<M: AnyMessage>(mut self, f: impl Fn((i32, i32)) -> M + 'static) -> Self {
+        self.on_context_menu = Some(Rc::new(move |position| Box::new(f(position))));
+        self
+    }
+
+    /// `f` receives the deltas and modifier keys of every wheel event on the
+    /// element. The page still scrolls, see
+    /// [`Node::on_wheel_prevent_default`] for zooming or panning instead.
+    pub fn on_wheel<M: AnyMessage>(mut self, f: impl Fn(WheelInfo) -> M + 'static) -> Self {
+        self.on_wheel = Some((Rc::new(move |wheel| Box::new(f(wheel))), false));
+        self
+    }
+
+    /// Like [`Node::on_wheel`], but keeps the wheel from scrolling the page
+    /// while the pointer is over the element.
+    pub fn on_wheel_prevent_default<M: AnyMessage>(
+        self,
+        f: impl Fn(WheelInfo) -> M + 'static,
+    ) -> Self {
+        let mut node = self.on_wheel(f);
+        if let Some((_, prevent_default)) = &mut node.on_wheel {
+            *prevent_default = true;
+        }
+        node
+    }
+
+    /// Calls `f` when `key` is pressed while the element or one of its
+    /// descendants has focus and exactly the `modifiers` are held, so
+    /// `Modifiers::NONE` doesn't fire for Shift + Enter. `key` is compared to
+    /// `KeyboardEvent.key` ignoring ASCII case, like `"Enter"`, `"Escape"` or
+    /// `"s"`. What the key would do otherwise, like inserting a newline, is
+    /// prevented. Can be called repeatedly to bind several keys.
+    pub fn on_key<M: AnyMessage>(
+        mut self,
+        key: &str,
+        modifiers: Modifiers,
+        f: impl Fn() -> M + 'static,
+    ) -> Self {
+        self.on_key.push((key.to_string(), modifiers, Rc::new(move || Box::new(f()))));
+        self
+    }
+
+    /// `f` receives the element's current value on every `input` event.
+    pub fn on_input<M: AnyMessage>(mut self, f: impl Fn(String) -> M + 'static) -> Self {
+        self.on_input = Some(Rc::new(move |value| Box::new(f(value))));
+        self
+    }
+
+    /// `f` is called when the element loses focus, like when the user tabs
+    /// out of an input they filled in.
+    pub fn on_blur<M: AnyMessage>(mut self, f: impl Fn() -> M + 'static) -> Self {
+        self.on_blur = Some(Rc::new(move || Box::new(f())));
+        self
+    }
+
+    /// `f` receives the plain text being pasted into the element, or an empty
+    /// string if the clipboard holds none. The browser still inserts it, see
+    /// [`Node::on_paste_prevent_default`] for inserting something else.
+    pub fn on_paste<M: AnyMessage>(mut self, f: impl Fn(String) -> M + 'static) -> Self {
+        self.on_paste = Some((Rc::new(move |text| Box::new(f(text))), false));
+        self
+    }
+
+    /// Like [`Node::on_paste`], but the browser doesn't insert anything.
+    pub fn on_paste_prevent_default<M: AnyMessage>(
+        self,
+        f: impl Fn(String) -> M + 'static,
+    ) -> Self {
+        let mut node = self.on_paste(f);
+        if let Some((_, prevent_default)) = &mut node.on_paste {
+            *prevent_default = true;
+        }
+        node
+    }
+
+    /// `f` is called whenever the user copies from inside the element.
+    pub fn on_copy<M: AnyMessage>(mut self, f: impl Fn() -> M + 'static) -> Self {
+        self.on_copy = Some((Rc::new(move || Box::new(f())), false));
+        self
+    }
+
+    /// Like [`Node::on_copy`], but the selection isn't copied, so the update
+    /// can put something else on the clipboard with
+    /// [`Effect::CopyToClipboard`](crate::Effect::CopyToClipboard).
+    pub fn on_copy_prevent_default<M: AnyMessage>(self, f: impl Fn() -> M + 'static) -> Self {
+        let mut node = self.on_copy(f);
+        if let Some((_, prevent_default)) = &mut node.on_copy {
+            *prevent_default = true;
+        }
+        node
+    }
+
+    /// `f` receives the element's `scrollTop` in pixels whenever it scrolls.
+    pub fn on_scroll<M: AnyMessage>(mut self, f: impl Fn(i32) -> M + 'static) -> Self {
+        self.on_scroll = Some(Rc::new(move |top| Box::new(f(top))));
+        self
+    }
+
+    /// `f` is called when the element comes into view, including when it is
+    /// rendered in view. It is called again only after the element left the
+    /// view, or after a render without `on_visible` on it.
+    pub fn on_visible<M: AnyMessage>(mut self, f: impl Fn() -> M + 'static) -> Self {
+        self.on_visible = Some(Rc::new(move || Box::new(f())));
+        self
+    }
+
+    /// `f` is called with `true` when at least `threshold` of the element
+    /// (between `0.0` and `1.0`) becomes visible in the viewport, and with
+    /// `false` when it drops below that again. It is also called once with
+    /// the initial state after the element is rendered.
+    ///
+    /// The observer is disconnected once the element is removed.
+    pub fn on_intersect<M: AnyMessage>(
+        mut self,
+        threshold: f64,
+        f: impl Fn(bool) -> M + 'static,
+    ) -> Self {
+        self.on_intersect = Some((threshold, Rc::new(move |visible| Box::new(f(visible)))));
+        self
+    }
+
+    /// Makes the element, the item at `index` of a list, draggable and calls
+    /// `f` with `index` when a drag starts on it. The index travels with the
+    /// drag to [`Node::on_drag_over`] and [`Node::on_drop`].
+    pub fn on_drag_start<M: AnyMessage>(
+        mut self,
+        index: usize,
+        f: impl Fn(usize) -> M + 'static,
+    ) -> Self {
+        self = self.with_attribute("draggable", "true");
+        self.on_drag_start = Some((index, Rc::new(move |index| Box::new(f(index)))));
+        self
+    }
+
+    /// `f` is called with the index of the dragged item and `index`, the one
+    /// of this element, while an item is dragged over it. Having this
+    /// handler is what allows dropping onto the element, since the runtime
+    /// calls `preventDefault` on the event. Drags that didn't start at an
+    /// [`Node::on_drag_start`], like of files, are ignored.
+    pub fn on_drag_over<M: AnyMessage>(
+        mut self,
+        index: usize,
+        f: impl Fn(usize, usize) -> M + 'static,
+    ) -> Self {
+        self.on_drag_over = Some(Rc::new(move |from| Box::new(f(from, index))));
+        self
+    }
+
+    /// `f` is called with the index of the dragged item and `index`, the one
+    /// of this element, when an item is dropped onto it, ready for
+    /// [`drag::reorder`](crate::drag::reorder). Only works together with
+    /// [`Node::on_drag_over`].
+    pub fn on_drop<M: AnyMessage>(
+        mut self,
+        index: usize,
+        f: impl Fn(usize, usize) -> M + 'static,
+    ) -> Self {
+        self.on_drop = Some(Rc::new(move |from| Box::new(f(from, index))));
+        self
+    }
+
+    /// `f` follows a drag with the left mouse button that starts on the
+    /// element, like moving a slider handle. Unlike [`Node::on_drag_start`]
+    /// nothing is picked up, and the moves are reported from the whole
+    /// document until the button is released, so the pointer may leave the
+    /// element.
+    pub fn on_mouse_drag<M: AnyMessage>(mut self, f: impl Fn(MouseDrag) -> M + 'static) -> Self {
+        self.on_mouse_drag = Some(Rc::new(move |drag| Box::new(f(drag))));
+        self
+    }
+
+    /// For `<input type="file">`: `f` receives the selected files every time
+    /// the selection changes. Read their contents with
+    /// [`Effect::ReadFileText`](crate::Effect::ReadFileText) or
+    /// [`Effect::ReadFileBytes`](crate::Effect::ReadFileBytes).
+    pub fn on_files<M: AnyMessage>(mut self, f: impl Fn(Vec<WebFile>) -> M + 'static) -> Self {
+        self.on_files = Some(Rc::new(move |files| Box::new(f(files))));
+        self
+    }
+
+    /// `f` receives the name of the CSS property whenever one of the
+    /// element's transitions finishes.
+    ///
+    /// `transitionend` bubbles, but only transitions of the element itself
+    /// are reported here, not those of its descendants.
+    pub fn on_transition_end<M: AnyMessage>(mut self, f: impl Fn(String) -> M + 'static) -> Self {
+        self.on_transition_end = Some(Rc::new(move |property| Box::new(f(property))));
+        self
+    }
+
+    /// `f` receives the `animation-name` whenever one of the element's CSS
+    /// animations finishes. Like [`Node::on_transition_end`], animations of
+    /// descendants are ignored even though the event bubbles.
+    pub fn on_animation_end<M: AnyMessage>(mut self, f: impl Fn(String) -> M + 'static) -> Self {
+        self.on_animation_end = Some(Rc::new(move |name| Box::new(f(name))));
+        self
+    }
+
+    /// `f` receives the width and height of the element's content box in
+    /// pixels once it is rendered and whenever its size changes afterwards.
+    ///
+    /// Uses a `ResizeObserver`, which is disconnected once the element is
+    /// removed. In browsers without one `f` is never called, so render
+    /// something sensible before the first size comes in.
+    pub fn on_resize<M: AnyMessage>(mut self, f: impl Fn(f64, f64) -> M + 'static) -> Self {
+        self.on_resize = Some(Rc::new(move |width, height| Box::new(f(width, height))));
+        self
+    }
+
+    /// Sets the current value of an `<input>`, `<select>` or `<textarea>`,
+    /// for inputs that are controlled by the model. Unlike the `value`
+    /// attribute, which is only the initial value, this keeps the element in
+    /// sync with every render.
+    ///
+    /// A textarea's value is its text content, so that is what it renders as
+    /// in [`Node::to_html`], replacing any children.
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Keeps keyboard focus inside this element for as long as it is
+    /// rendered, e.g. for modals. When it stops being rendered, focus goes
+    /// back to the element that had it before the trap was rendered.
+    pub fn with_focus_trap(mut self) -> Self {
+        self.focus_trap = true;
+        self
+    }
+
+    /// Focuses the element after it is rendered if one of its siblings has
+    /// focus, so focus follows the item that became active in a group like
+    /// [`roving`](crate::roving) renders.
+    pub fn with_roving_focus(mut self) -> Self {
+        self.roving_focus = true;
+        self
+    }
+}
+
+impl Node {
+    /// Where attributes and children of the node go: for components the root
+    /// of what they rendered, which is all that ends up in the DOM.
+    fn host(&mut self) -> &mut Node {
+        match self.kind {
+            NodeKind::Custom {
+                ref mut rendered, ..
+            } => rendered.host(),
+            _ => self,
+        }
+    }
+
+    /// Updates how many children were added to a component node, which are
+    /// kept when it renders again.
+    fn count_added(&mut self, count: impl FnOnce(usize) -> usize) {
+        if let NodeKind::Custom { added, .. } = &mut self.kind {
+            *added = count(*added);
+        }
+    }
+
+    /// Inserts `child` before the child currently at `index`. Indices past the
+    /// end are clamped, so the child is appended instead of panicking.
+    ///
+    /// On a component node the children it rendered come first and are
+    /// replaced when it renders again, so `child` can only go among the ones
+    /// added to it: indices into the rendered children insert it in front
+    /// of the first added one.
+    pub fn insert_child(&mut self, index: usize, child: Node) {
+        let is_component = matches!(self.kind, NodeKind::Custom { .. });
+        let added = self.added();
+        let children = &mut self.host().children;
+        let first = if is_component { children.len() - added } else { 0 };
+        let index = index.clamp(first, children.len());
+        children.insert(index, child);
+        self.count_added(|added| added + 1);
+    }
+
+    /// Removes and returns the child at `index`, or `None` if there is no
+    /// child at that index.
+    pub fn remove_child(&mut self, index: usize) -> Option<Node> {
+        let children = &mut self.host().children;
+        if index >= children.len() {
+            return None;
+        }
+        let rendered = children.len().saturating_sub(self.added());
+        let child = self.host().children.remove(index);
+        if index >= rendered {
+            self.count_added(|added| added - 1);
+        }
+        Some(child)
+    }
+
+    /// Replaces all children. For a component node the new ones count as
+    /// added, they stay when it renders again, behind what it renders.
+    pub fn replace_children(&mut self, children: Vec<Node>) {
+        self.count_added(|_| children.len());
+        self.host().children = children;
+    }
+
+    fn added(&self) -> usize {
+        match self.kind {
+            NodeKind::Custom { added, .. } => added,
+            _ => 0,
+        }
+    }
+
+    /// Overlays `other` onto this node's attributes. Values from `other` win,
+    /// except for `class` and `style`, which are concatenated with this
+    /// node's values first and `other`'s values after.
+    pub fn merge_attributes(&mut self, other: &Attributes) {
+        // A component node keeps what was added to it, to add it again when
+        // the component renders again.
+        if let NodeKind::Custom { .. } = self.kind {
+            merge(&mut self.attributes, other);
+        }
+        merge(&mut self.host().attributes, other);
+    }
+
+    /// Renders every component in the tree again, so each one shows its
+    /// current state, even if it changed after its node was created. The
+    /// attributes and children added to a component node are added to what
+    /// it renders now. The runtime does this on every render.
+    pub fn refresh(&mut self) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            let (component, rendered, added) = match &mut node.kind {
+                NodeKind::Custom {
+                    component,
+                    rendered,
+                    added,
+                } => (component, rendered, *added),
+                _ => {
+                    stack.extend(&mut node.children);
+                    continue;
+                }
+            };
+
+            let old = rendered.host();
+            let kept = old.children.split_off(old.children.len().saturating_sub(added));
+            let mut fresh = component.view();
+            fresh.merge_attributes(&node.attributes);
+            fresh.host().children.extend(kept);
+            **rendered = fresh;
+
+            // Components the component rendered are as fresh as it is, but
+            // the added children were made before.
+            let children = &mut rendered.host().children;
+            let at = children.len().saturating_sub(added);
+            stack.extend(&mut children[at..]);
+        }
+    }
+}
+
+/// Overlays `other` onto `attributes`, see [`Node::merge_attributes`].
+fn merge(attributes: &mut Attributes, other: &Attributes) {
+    for (&key, value) in other {
+        let merged = match (key, attributes.get(key)) {
+            ("class", Some(current)) => format!("{} {}", current.as_text(), value.as_text()).into(),
+            ("style", Some(current)) => format!(
+                "{}; {}",
+                current.as_text().trim_end().trim_end_matches(';'),
+                value.as_text()
+            )
+            .into(),
+            _ => value.clone(),
+        };
+
+        attributes.insert(key, merged);
+    }
+}
+
+impl Node {
+    /// Compares everything that ends up in the DOM. Handlers can't be
+    /// compared, so only their presence is.
+    pub fn same_structure(&self, other: &Node) -> bool {
+        let mut pairs = vec![(self, other)];
+
+        while let Some((a, b)) = pairs.pop() {
+            if !a.same_node(b) {
+                return false;
+            }
+            if let (NodeKind::Custom { rendered: x, .. }, NodeKind::Custom { rendered: y, .. }) =
+                (&a.kind, &b.kind)
+            {
+                pairs.push((x, y));
+            }
+            pairs.extend(a.children.iter().zip(&b.children));
+        }
+        true
+    }
+
+    /// [`Node::same_structure`] without comparing the children and what
+    /// components rendered.
+    fn same_node(&self, other: &Node) -> bool {
+        let same_kind = match (&self.kind, &other.kind) {
+            (NodeKind::Native { tag: a }, NodeKind::Native { tag: b }) => a == b,
+            (NodeKind::Text(a), NodeKind::Text(b)) => a == b,
+            (NodeKind::Custom { .. }, NodeKind::Custom { .. }) => true,
+            _ => false,
+        };
+
+        same_kind
+            && self.key == other.key
+            && self.on_click.is_some() == other.on_click.is_some()
+            && self.on_double_click.is_some() == other.on_double_click.is_some()
+            && self.on_context_menu.is_some() == other.on_context_menu.is_some()
+            && self.on_wheel.as_ref().map(|(_, prevent_default)| prevent_default)
+                == other.on_wheel.as_ref().map(|(_, prevent_default)| prevent_default)
+            && self.on_key.iter().map(|(key, modifiers, _)| (key, modifiers))
+                .eq(other.on_key.iter().map(|(key, modifiers, _)| (key, modifiers)))
+            && self.on_input.is_some() == other.on_input.is_some()
+            && self.on_blur.is_some() == other.on_blur.is_some()
+            && self.on_paste.as_ref().map(|(_, prevent_default)| prevent_default)
+                == other.on_paste.as_ref().map(|(_, prevent_default)| prevent_default)
+            && self.on_copy.as_ref().map(|(_, prevent_default)| prevent_default)
+                == other.on_copy.as_ref().map(|(_, prevent_default)| prevent_default)
+            && self.on_scroll.is_some() == other.on_scroll.is_some()
+            && self.on_visible.is_some() == other.on_visible.is_some()
+            && self.on_intersect.as_ref().map(|(threshold, _)| threshold)
+                == other.on_intersect.as_ref().map(|(threshold, _)| threshold)
+            && self.on_drag_start.as_ref().map(|(index, _)| index)
+                == other.on_drag_start.as_ref().map(|(index, _)| index)
+            && self.on_drag_over.is_some() == other.on_drag_over.is_some()
+            && self.on_drop.is_some() == other.on_drop.is_some()
+            && self.on_mouse_drag.is_some() == other.on_mouse_drag.is_some()
+            && self.on_files.is_some() == other.on_files.is_some()
+            && self.on_transition_end.is_some() == other.on_transition_end.is_some()
+            && self.on_animation_end.is_some() == other.on_animation_end.is_some()
+            && self.on_resize.is_some() == other.on_resize.is_some()
+            && self.focus_trap == other.focus_trap
+            && self.roving_focus == other.roving_focus
+            && self.value == other.value
+            && self.attributes.len() == other.attributes.len()
+            && self.attributes.iter().all(|(key, value)| {
+                other
+                    .attributes
+                    .get(key)
+                    .is_some_and(|other| other.as_text() == value.as_text())
+            })
+            && self.children.len() == other.children.len()
+    }
+
+    /// The [`Hash`] of the tree as a number. Comparing it with the one of the
+    /// previous tree tells cheaply whether a view changed. Only stable within
+    /// one run of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The number of nodes on the longest path from this node down to a
+    /// leaf, counting both, so a single text node has a depth of 1.
+    /// Components count as what they rendered, like in the DOM.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut stack = vec![(self, 1)];
+
+        while let Some((node, level)) = stack.pop() {
+            match &node.kind {
+                NodeKind::Custom { rendered, .. } => stack.push((rendered, level)),
+                _ => {
+                    depth = depth.max(level);
+                    stack.extend(node.children.iter().map(|child| (child, level + 1)));
+                }
+            }
+        }
+        depth
+    }
+
+    /// The number of nodes in the tree, including this one. Components count
+    /// as what they rendered, like in the DOM.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            match &node.kind {
+                NodeKind::Custom { rendered, .. } => stack.push(rendered),
+                _ => {
+                    count += 1;
+                    stack.extend(&node.children);
+                }
+            }
+        }
+        count
+    }
+
+    /// The attributes ordered by key, byte-wise, for tools that need an
+    /// order that doesn't depend on how the view set them, like a
+    /// serializer producing comparable output. Every key appears once, with
+    /// the value it has now. Components yield the attributes of the element
+    /// they rendered.
+    pub fn attributes_sorted(&self) -> impl Iterator<Item = (&str, &NodeAttributeValue)> {
+        let mut attributes: Vec<_> = crate::diff::resolve(self)
+            .attributes
+            .iter()
+            .map(|(&key, value)| (key, value))
+            .collect();
+        attributes.sort_unstable_by_key(|&(key, _)| key);
+        attributes.into_iter()
+    }
+}
+
+/// Hashes the parts of the tree [`Node::same_structure`] compares by value:
+/// tags, text, keys, values and attributes, in any order. Whether handlers
+/// are present is left out, so trees that only differ in them hash equal.
+/// Hashes the nodes in document order, each followed by how many children it
+/// has, with an explicit stack so no tree is too deep for it.
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            match &node.kind {
+                NodeKind::Native { tag } => (0u8, tag).hash(state),
+                NodeKind::Text(text) => (1u8, text).hash(state),
+                NodeKind::Custom { rendered, .. } => {
+                    stack.push(rendered);
+                    continue;
+                }
+            }
+
+            let mut attributes: Vec<_> = node
+                .attributes
+                .iter()
+                .map(|(key, value)| (*key, value.as_text()))
+                .collect();
+            attributes.sort_unstable();
+
+            node.key.hash(state);
+            node.value.hash(state);
+            node.focus_trap.hash(state);
+            node.roving_focus.hash(state);
+            attributes.hash(state);
+            node.children.len().hash(state);
+            stack.extend(node.children.iter().rev());
+        }
+    }
+}
+
+/// Takes the descendants apart one by one, dropping a deeply nested tree
+/// recursively could overflow the stack.
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut descendants = std::mem::take(&mut self.children);
+
+        while let Some(mut node) = descendants.pop() {
+            descendants.append(&mut node.children);
+        }
+    }
+}
+
+/// Options for [`Node::to_html_with`]. The defaults produce the same output
+/// as [`Node::to_html`].
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Emits well-formed XHTML: tags are lowercased, elements without
+    /// children self-close like `<br/>`, all attribute values are quoted and
+    /// boolean attributes are written as `disabled="disabled"`. False ones
+    /// are left out in either mode.
+    pub xhtml: bool,
+    /// Prepends `<?xml version="1.0" encoding="UTF-8"?>`.
+    pub xml_declaration: bool,
+    /// Emits attributes sorted by name instead of in the order they were
+    /// set, which makes diffs of rendered output easier to read.
+    pub sort_attributes: bool,
+    /// How many levels of elements are rendered, [`RenderOptions::MAX_DEPTH`]
+    /// by default. The children of elements at the last level are replaced
+    /// with a `<!-- truncated -->` comment. Rendering doesn't recurse, so any
+    /// depth is safe, but the indentation of a deeply nested tree, like one
+    /// built from user content, grows with the square of its depth. With
+    /// the `dev` feature truncating is reported in the console.
+    pub max_depth: usize,
+    /// Puts every attribute of a tag on a line of its own, indented below
+    /// the tag, if the tag would be wider than this many characters
+    /// otherwise. The `>` follows the last attribute. Tags inside of `<pre>`,
+    /// `<textarea>` and `<code>` are never wrapped.
+    pub max_line_width: Option<usize>,
+}
+
+impl RenderOptions {
+    /// The default [`RenderOptions::max_depth`], deeper than any page that
+    /// is written by hand.
+    pub const MAX_DEPTH: usize = 512;
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            xhtml: false,
+            xml_declaration: false,
+            sort_attributes: false,
+            max_depth: Self::MAX_DEPTH,
+            max_line_width: None,
+        }
+    }
+}
+
+/// What [`Node::write_html`] has left to write, the next step last.
+enum Html<'a> {
+    /// A node, with its indent, `None` inside of verbatim content, and its
+    /// depth.
+    Node(&'a Node, Option<usize>, usize),
+    Newline,
+    /// A closing tag, on a line of its own at the indent if there is one.
+    Close(String, Option<usize>),
+}
+
+fn push_indent(out: &mut String, indent: usize) {
+    out.extend(std::iter::repeat_n(' ', indent));
+}
+
+fn render_attribute(
+    key: &str,
+    value: &NodeAttributeValue,
+    options: &RenderOptions,
+) -> Option<String> {
+    let value = match (key, value) {
+        // Inline styles are assembled from several places, only the
+        // declarations that take effect are kept.
+        ("style", value) => NodeAttributeValue::from(css::normalize_style(&value.as_text())),
+        (_, NodeAttributeValue::Boolean(false)) => return None,
+        (_, NodeAttributeValue::Boolean(true)) if options.xhtml => key.into(),
+        (_, NodeAttributeValue::Number(x)) if options.xhtml => x.to_string().into(),
+        (_, value) => value.clone(),
+    };
+
+    Some(format!("{}={}", key, value))
+}
+
+impl Node {
+    pub fn to_html(&self) -> String {
+        self.to_html_with(&RenderOptions::default())
+    }
+
+    pub fn to_html_with(&self, options: &RenderOptions) -> String {
+        let mut html = String::with_capacity(self.estimate_html_len(options.max_depth) + 64);
+
+        if options.xml_declaration {
+            html.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        }
+
+        self.write_html(&mut html, options);
+        html
+    }
+
+    /// Roughly how long the HTML of the node is, so it can be rendered into
+    /// a buffer that rarely has to grow.
+    fn estimate_html_len(&self, max_depth: usize) -> usize {
+        let mut len = 0;
+        let mut stack = vec![(self, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            match &node.kind {
+                NodeKind::Text(value) => len += depth * 2 + value.len(),
+                NodeKind::Custom { rendered, .. } => stack.push((rendered, depth)),
+                NodeKind::Native { .. } if depth >= max_depth => {}
+                NodeKind::Native { tag } => {
+                    let attributes: usize = node
+                        .attributes
+                        .iter()
+                        .map(|(key, value)| match value {
+                            NodeAttributeValue::String(value) => key.len() + value.len() + 4,
+                            _ => key.len() + 7,
+                        })
+                        .sum();
+
+                    len += depth * 6 + tag.len() * 2 + 6 + attributes + node.children.len();
+                    stack.extend(node.children.iter().map(|child| (child, depth + 1)));
+                }
+            }
+        }
+        len
+    }
+
+    /// Appends the HTML of the node to `out`. The tree is walked with an
+    /// explicit stack instead of recursively, so no tree is too deep for it.
+    fn write_html(&self, out: &mut String, options: &RenderOptions) {
+        let mut stack = vec![Html::Node(self, Some(0), 1)];
+
+        while let Some(step) = stack.pop() {
+            match step {
+                Html::Node(node, indent, depth) => {
+                    node.write_open(out, options, indent, depth, &mut stack)
+                }
+                Html::Newline => out.push('\n'),
+                Html::Close(tag, indent) => {
+                    if let Some(indent) = indent {
+                        out.push('\n');
+                        push_indent(out, indent);
+                    }
+                    out.push_str("</");
+                    out.push_str(&tag);
+                    out.push('>');
+                }
+            }
+        }
+    }
+
+    /// Writes the node at `depth` up to its content, with every line
+    /// indented by `indent` spaces, and pushes what is left onto `stack`.
+    /// Inside of `<pre>`, `<textarea>` and `<code>` whitespace is
+    /// significant, so their content is written with no indent at all,
+    /// which leaves it as is.
+    fn write_open<'a>(
+        &'a self,
+        out: &mut String,
+        options: &RenderOptions,
+        indent: Option<usize>,
+        depth: usize,
+        stack: &mut Vec<Html<'a>>,
+    ) {
+        let tag = match &self.kind {
+            NodeKind::Text(value) => {
+                match indent {
+                    Some(indent) => {
+                        for (i, line) in value.split('\n').enumerate() {
+                            if i > 0 {
+                                out.push('\n');
+                            }
+                            push_indent(out, indent);
+                            escape_into(out, line);
+                        }
+                    }
+                    None => escape_into(out, value),
+                }
+                return;
+            }
+            NodeKind::Custom { rendered, .. } => {
+                stack.push(Html::Node(rendered, indent, depth));
+                return;
+            }
+            NodeKind::Native { tag } if options.xhtml => tag.to_lowercase(),
+            NodeKind::Native { tag } => tag.clone(),
+        };
+
+        let textarea = tag.eq_ignore_ascii_case("textarea");
+        let value = self.value.as_deref().map(NodeAttributeValue::from);
+        // A textarea's value is its content.
+        let content = self.value.as_deref().filter(|_| textarea);
+        let has_children = content.is_some() || !self.children.is_empty();
+
+        // A select's value is whichever option has the `selected` attribute.
+        let select = tag.eq_ignore_ascii_case("select");
+
+        let mut attributes: Vec<_> = self.attributes.iter().collect();
+        if let Some(value) = value.as_ref().filter(|_| !textarea && !select) {
+            if !self.attributes.contains_key("value") {
+                attributes.push((&"value", value));
+            }
+        }
+        if options.sort_attributes {
+            attributes.sort_by_key(|(key, _)| **key);
+        }
+
+        let verbatim = ["pre", "textarea", "code"].contains(&&*tag.to_lowercase());
+        let in_verbatim = indent.is_none();
+        let (indent, verbatim) = match indent {
+            Some(indent) => (indent, verbatim),
+            None => (0, true),
+        };
+
+        let attributes: Vec<_> = attributes
+            .into_iter()
+            .filter_map(|(key, val)| render_attribute(key, val, options))
+            .collect();
+        let width = indent + tag.len() + attributes.iter().map(|x| x.len() + 1).sum::<usize>() + 3;
+        let wrap = options
+            .max_line_width
+            .is_some_and(|max| width > max && !in_verbatim && !attributes.is_empty());
+
+        push_indent(out, indent);
+        out.push('<');
+        out.push_str(&tag);
+        for attribute in &attributes {
+            if wrap {
+                out.push('\n');
+                push_indent(out, indent + 4);
+            } else {
+                out.push(' ');
+            }
+            out.push_str(attribute);
+        }
+
+        if options.xhtml && !has_children {
+            out.push_str("/>");
+            return;
+        }
+        out.push('>');
+
+        let truncated = depth >= options.max_depth && has_children;
+
+        #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+        if truncated {
+            crate::warn(&format!(
+                "the tree is nested deeper than {} levels, truncating the children of a <{}>",
+                options.max_depth, tag
+            ));
+        }
+
+        if truncated && verbatim {
+            out.push_str("<!-- truncated -->");
+            stack.push(Html::Close(tag, None));
+        } else if truncated {
+            out.push('\n');
+            push_indent(out, indent + 2);
+            out.push_str("<!-- truncated -->");
+            stack.push(Html::Close(tag, Some(indent)));
+        } else if verbatim {
+            stack.push(Html::Close(tag, None));
+            match content {
+                Some(content) => escape_into(out, content),
+                None => {
+                    let children = self.children.iter().rev();
+                    stack.extend(children.map(|child| Html::Node(child, None, depth + 1)));
+                }
+            }
+        } else if self.children.is_empty() {
+            out.push('\n');
+            push_indent(out, indent);
+            stack.push(Html::Close(tag, Some(indent)));
+        } else {
+            out.push('\n');
+            stack.push(Html::Close(tag, Some(indent)));
+            for (i, child) in self.children.iter().enumerate().rev() {
+                stack.push(Html::Node(child, Some(indent + 2), depth + 1));
+                if i > 0 {
+                    stack.push(Html::Newline);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(items: &[&str]) -> Node {
+        items
+            .iter()
+            .fold(Node::native("ul"), |list, item| list.with_child(Node::text(*item)))
+    }
+
+    fn texts(node: &Node) -> Vec<String> {
+        node.children.iter().map(Node::to_html).collect()
+    }
+
+    #[test]
+    fn insert_child_at_index() {
+        let mut node = list(&["a", "c"]);
+        node.insert_child(1, Node::text("b"));
+        node.insert_child(0, Node::text("first"));
+
+        assert_eq!(texts(&node), ["first", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn insert_child_past_end_appends() {
+        let node = list(&["a"]).with_child_at(5, Node::text("b"));
+
+        assert_eq!(texts(&node), ["a", "b"]);
+    }
+
+    #[test]
+    fn remove_child_at_index() {
+        let mut node = list(&["a", "b", "c"]);
+
+        assert_eq!(node.remove_child(1).map(|child| child.to_html()).as_deref(), Some("b"));
+        assert!(node.remove_child(2).is_none());
+        assert_eq!(texts(&node), ["a", "c"]);
+    }
+
+    #[test]
+    fn replace_children_drops_old_ones() {
+        let mut node = list(&["a", "b"]);
+        node.replace_children(vec![Node::text("c")]);
+
+        assert_eq!(texts(&node), ["c"]);
+    }
+
+    #[test]
+    fn merge_attributes_overrides_and_concatenates() {
+        let mut node = Node::native("div")
+            .id("a")
+            .with_attribute("class", "card")
+            .with_attribute("style", "color: red;");
+        let other = Node::native("div")
+            .id("b")
+            .with_attribute("class", "active")
+            .with_attribute("style", "margin: 0")
+            .with_attribute("title", "Card");
+        node.merge_attributes(&other.attributes);
+
+        let text = |key: &str| node.attributes[key].as_text();
+        assert_eq!(text("id"), "b");
+        assert_eq!(text("class"), "card active");
+        assert_eq!(text("style"), "color: red; margin: 0");
+        assert_eq!(text("title"), "Card");
+    }
+
+    #[test]
+    fn text_of_displays_value() {
+        assert_eq!(Node::text_of(42).to_html(), "42");
+        assert_eq!(Node::text_of(1.5).to_html(), "1.5");
+        assert_eq!(Node::text_of('x').to_html(), Node::text("x").to_html());
+
+        struct Price(u32);
+
+        impl Display for Price {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}.{:02} <EUR> & tax", self.0 / 100, self.0 % 100)
+            }
+        }
+
+        let price = Node::text_of(Price(1999));
+        assert!(matches!(&price.kind, NodeKind::Text(text) if text == "19.99 <EUR> & tax"));
+        assert_eq!(price.to_html(), "19.99 &lt;EUR&gt; &amp; tax");
+    }
+
+    #[test]
+    fn drop_receives_source_and_target() {
+        let item = Node::native("li")
+            .on_drag_start(3, |from| (from, from))
+            .on_drop(1, |from, to| (from, to));
+
+        let msg: Box<dyn std::any::Any> = (item.on_drop.as_ref().unwrap())(3);
+        assert_eq!(*msg.downcast::<(usize, usize)>().unwrap(), (3, 1));
+        assert_eq!(item.on_drag_start.as_ref().map(|(index, _)| *index), Some(3));
+        assert_eq!(item.attributes["draggable"].as_text(), "true");
+    }
+
+    fn form() -> Node {
+        Node::native("DIV")
+            .with_child(Node::native("BR"))
+            .with_child(Node::native("input").disabled(true).with_attribute("size", 3))
+    }
+
+    #[test]
+    fn html_keeps_tags_as_written() {
+        let html = form().to_html();
+
+        assert!(html.starts_with("<DIV>\n  <BR>"));
+        assert!(html.contains("<input disabled=true size=3>"));
+        assert!(!html.contains("/>"));
+    }
+
+    #[test]
+    fn xhtml_self_closes_and_quotes() {
+        let options = RenderOptions {
+            xhtml: true,
+            xml_declaration: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            form().to_html_with(&options),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <div>\n  <br/>\n  <input disabled=\"disabled\" size=\"3\"/>\n</div>"
+        );
+    }
+
+    #[test]
+    fn sort_attributes_orders_by_key() {
+        let node = Node::native("p")
+            .with_attribute("z", "1")
+            .with_attribute("a", "2")
+            .with_attribute("m", "3");
+        let sorted = RenderOptions {
+            sort_attributes: true,
+            ..RenderOptions::default()
+        };
+
+        assert!(node.to_html_with(&sorted).starts_with(r#"<p a="2" m="3" z="1">"#));
+        assert!(node.to_html().starts_with(r#"<p z="1" a="2" m="3">"#));
+    }
+
+    struct Greeting;
+
+    impl Component for Greeting {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            Node::text("Hello")
+        }
+
+        fn update(&mut self, _: ()) -> Option<crate::Effect> {
+            None
+        }
+    }
+
+    fn avatar(url: &'static str) -> Node {
+        Node::native("img").src(url)
+    }
+
+    #[test]
+    fn debug_output_names_components() {
+        let debug = format!("{:?}", Node::custom(Box::new(Greeting)));
+        assert!(debug.contains(&format!("name: {:?}", std::any::type_name::<Greeting>())));
+
+        let debug = format!("{:?}", Node::pure(avatar, "me.png"));
+        assert!(debug.contains("avatar"));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Msg {
+        Increment,
+    }
+
+    #[test]
+    fn on_click_dispatches_same_message_every_time() {
+        let button = Node::native("button").on_click(Msg::Increment);
+        let on_click = button.on_click.clone().unwrap();
+
+        for _ in 0..2 {
+            let msg: Box<dyn std::any::Any> = on_click();
+            assert_eq!(*msg.downcast::<Msg>().unwrap(), Msg::Increment);
+        }
+    }
+
+    #[test]
+    fn textarea_value_renders_as_content() {
+        let textarea = Node::native("textarea")
+            .with_child(Node::text("ignored"))
+            .with_value("Dear <team>")
+            .on_input(|draft: String| draft);
+
+        let html = textarea.to_html();
+        assert_eq!(html, "<textarea>Dear &lt;team&gt;</textarea>");
+        assert!(!html.contains("value="));
+
+        let on_input = textarea.on_input.as_ref().unwrap();
+        let msg: Box<dyn std::any::Any> = on_input("Dear team".to_string());
+        assert_eq!(*msg.downcast::<String>().unwrap(), "Dear team");
+    }
+
+    #[test]
+    fn input_value_renders_as_attribute() {
+        let html = Node::native("input").with_value("Ada").to_html();
+
+        assert!(html.starts_with(r#"<input value="Ada">"#));
+    }
+
+    #[test]
+    fn number_clamped_into_range() {
+        let colspan = |value| {
+            Node::native("td")
+                .with_number_clamped("colspan", value, 1, 1000)
+                .attributes["colspan"]
+                .as_text()
+        };
+
+        assert_eq!(colspan(3), "3");
+        assert_eq!(colspan(-2), "1");
+        assert_eq!(colspan(5000), "1000");
+    }
+
+    #[test]
+    #[should_panic]
+    fn number_clamped_with_empty_range_panics() {
+        Node::native("td").with_number_clamped("colspan", 1, 2, 1);
+    }
+
+    fn card(title: &str) -> Node {
+        Node::native("article")
+            .with_attribute("class", "card")
+            .with_child(Node::native("h2").with_child(Node::text(title)))
+    }
+
+    #[test]
+    fn identical_trees_hash_equal() {
+        assert_eq!(card("News").fingerprint(), card("News").fingerprint());
+        // Handlers are left out.
+        let clickable = card("News").on_click(Msg::Increment);
+        assert_eq!(clickable.fingerprint(), card("News").fingerprint());
+    }
+
+    #[test]
+    fn changes_hash_differently() {
+        let changed = card("News").with_attribute("class", "card active");
+
+        assert_ne!(changed.fingerprint(), card("News").fingerprint());
+        assert_ne!(card("Sports").fingerprint(), card("News").fingerprint());
+    }
+
+    fn interactive() -> Vec<(&'static str, NodeAttributeValue)> {
+        vec![("role", "button".into()), ("tabindex", 0.into())]
+    }
+
+    #[test]
+    fn with_attributes_spreads_the_group() {
+        let node = Node::native("div")
+            .with_attribute("role", "note")
+            .with_attributes(interactive());
+
+        assert_eq!(node.attributes.len(), 2);
+        assert_eq!(node.attributes["role"].as_text(), "button");
+        assert_eq!(node.attributes["tabindex"].as_text(), "0");
+    }
+
+    #[test]
+    fn with_attributes_if_skips_the_group() {
+        let node = Node::native("div").with_attributes_if(false, interactive());
+        assert!(node.attributes.is_empty());
+
+        let node = Node::native("div").with_attributes_if(true, interactive());
+        assert_eq!(node.attributes.len(), 2);
+    }
+
+    #[test]
+    fn pre_content_is_kept_verbatim() {
+        let node = Node::native("div").with_child(
+            Node::native("pre")
+                .with_child(Node::text("  indented\n    more"))
+                .with_child(Node::native("b").with_child(Node::text(" bold"))),
+        );
+
+        assert_eq!(
+            node.to_html(),
+            "<div>\n  <pre>  indented\n    more<b> bold</b></pre>\n</div>"
+        );
+    }
+
+    #[test]
+    fn boolean_helpers_toggle_attributes() {
+        type Helper = fn(Node, bool) -> Node;
+        let helpers: [(&str, Helper); 5] = [
+            ("disabled", Node::disabled),
+            ("checked", Node::checked),
+            ("readonly", Node::readonly),
+            ("required", Node::required),
+            ("hidden", Node::hidden),
+        ];
+
+        for (key, helper) in helpers {
+            let on = helper(Node::native("input"), true);
+            assert_eq!(on.attributes[key], NodeAttributeValue::Boolean(true), "{}", key);
+            assert!(on.to_html().starts_with(&format!("<input {}=true>", key)));
+
+            let off = helper(on, false);
+            assert!(!off.attributes.contains_key(key), "{}", key);
+            assert!(off.to_html().starts_with("<input>"), "{}", key);
+        }
+    }
+
+    #[test]
+    fn false_boolean_attributes_are_not_rendered() {
+        let node = Node::native("input").with_attribute("checked", false);
+        let xhtml = RenderOptions {
+            xhtml: true,
+            ..RenderOptions::default()
+        };
+
+        assert!(node.to_html().starts_with("<input>"));
+        assert_eq!(node.to_html_with(&xhtml), "<input/>");
+    }
+
+    #[test]
+    fn attribute_builders_set_their_attribute() {
+        let link = Node::native("a").href("/docs").title("Docs").id("docs");
+        let image = Node::native("img").src("/logo.png").alt("Logo");
+        let input = Node::native("input")
+            .type_("number")
+            .name("amount")
+            .placeholder("0")
+            .value(3);
+
+        assert_eq!(link.attributes["href"], NodeAttributeValue::from("/docs"));
+        assert_eq!(link.attributes["title"], NodeAttributeValue::from("Docs"));
+        assert_eq!(link.attributes["id"], NodeAttributeValue::from("docs"));
+        assert_eq!(image.attributes["src"], NodeAttributeValue::from("/logo.png"));
+        assert_eq!(image.attributes["alt"], NodeAttributeValue::from("Logo"));
+        assert_eq!(input.attributes["type"], NodeAttributeValue::from("number"));
+        assert_eq!(input.attributes["name"], NodeAttributeValue::from("amount"));
+        assert_eq!(input.attributes["placeholder"], NodeAttributeValue::from("0"));
+        assert_eq!(input.attributes["value"], NodeAttributeValue::Number(3));
+    }
+
+    #[test]
+    fn attribute_values_compare_by_variant_and_value() {
+        use NodeAttributeValue::*;
+
+        assert_eq!(String("a".into()), String("a".into()));
+        assert_ne!(String("a".into()), String("b".into()));
+        assert_eq!(Number(1), Number(1));
+        assert_ne!(Number(1), Number(2));
+        assert_eq!(Boolean(true), Boolean(true));
+        assert_ne!(Boolean(true), Boolean(false));
+
+        assert_ne!(Number(1), String("1".into()));
+        assert_ne!(Boolean(true), String("true".into()));
+        assert_eq!(Number(1).as_text(), String("1".into()).as_text());
+    }
+
+    fn wide_table(rows: usize) -> Node {
+        (0..rows).fold(Node::native("table"), |table, i| {
+            table.with_child(
+                Node::native("tr")
+                    .id(format!("row-{}", i))
+                    .with_child(Node::native("td").with_child(Node::text(format!("cell {}", i))))
+                    .with_child(Node::native("td").with_child(Node::text("x"))),
+            )
+        })
+    }
+
+    /// How `to_html` used to render, one `format!` per element, kept to
+    /// compare against. Only covers what [`wide_table`] uses.
+    fn format_html(node: &Node, indent: &str) -> String {
+        let tag = match &node.kind {
+            NodeKind::Text(value) => {
+                return escape(value)
+                    .split('\n')
+                    .map(|line| format!("{}{}", indent, line))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            NodeKind::Native { tag } => tag,
+            NodeKind::Custom { .. } => unreachable!(),
+        };
+
+        let attributes = node
+            .attributes
+            .iter()
+            .filter_map(|(key, val)| render_attribute(key, val, &RenderOptions::default()))
+            .reduce(|acc, x| format!("{} {}", acc, x))
+            .map(|attributes| format!(" {}", attributes))
+            .unwrap_or_default();
+
+        let children = if node.children.is_empty() {
+            indent.to_string()
+        } else {
+            let indent = format!("{}  ", indent);
+            node.children
+                .iter()
+                .map(|child| format_html(child, &indent))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        format!("{}<{}{}>\n{}\n{}</{}>", indent, tag, attributes, children, indent, tag)
+    }
+
+    #[test]
+    fn wide_tree_renders_like_the_format_based_renderer() {
+        let table = wide_table(200);
+
+        assert_eq!(table.to_html(), format_html(&table, ""));
+    }
+
+    /// Run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_wide_tree_rendering() {
+        let table = wide_table(20_000);
+        let time = |render: &dyn Fn() -> String| {
+            let start = std::time::Instant::now();
+            let len: usize = (0..10).map(|_| render().len()).sum();
+            (start.elapsed() / 10, len / 10)
+        };
+
+        let (buffered, len) = time(&|| table.to_html());
+        let (formatted, _) = time(&|| format_html(&table, ""));
+        println!("{} bytes: to_html {:?}, format! {:?}", len, buffered, formatted);
+    }
+
+    #[test]
+    fn depth_and_node_count_of_a_known_tree() {
+        let tree = Node::native("main")
+            .with_child(Node::native("h1").with_child(Node::text("Title")))
+            .with_child(
+                Node::native("ul")
+                    .with_child(Node::native("li").with_child(Node::text("1")))
+                    .with_child(Node::native("li").with_child(Node::text("2"))),
+            );
+
+        assert_eq!(tree.depth(), 4);
+        assert_eq!(tree.node_count(), 8);
+        assert_eq!(Node::text("leaf").depth(), 1);
+        assert_eq!(Node::text("leaf").node_count(), 1);
+        let page = Node::native("main").with_child(Node::custom(Box::new(Greeting)));
+        assert_eq!(page.depth(), 2);
+        assert_eq!(page.node_count(), 2);
+    }
+
+    #[test]
+    fn deep_chain_does_not_overflow_the_stack() {
+        // Inside of a <pre> nothing is indented, keeping the HTML small.
+        let chain = || {
+            let chain = (0..10_000)
+                .fold(Node::text("leaf"), |child, _| Node::native("span").with_child(child));
+            Node::native("pre").with_child(chain)
+        };
+        let (a, b) = (chain(), chain());
+        let unlimited = RenderOptions {
+            max_depth: usize::MAX,
+            ..RenderOptions::default()
+        };
+
+        let html = a.to_html_with(&unlimited);
+        assert_eq!(html.matches("<span>").count(), 10_000);
+        assert!(html.contains("<span>leaf</span>"));
+        assert!(a.to_html().contains("<!-- truncated -->"));
+        assert_eq!(a.depth(), 10_002);
+        assert_eq!(a.node_count(), 10_002);
+        assert!(a.same_structure(&b));
+        assert_eq!(a.fingerprint(), b.fingerprint());
+    }
+
+    #[test]
+    fn long_tags_wrap_their_attributes() {
+        let form = Node::native("form").with_child(
+            Node::native("input")
+                .type_("email")
+                .name("email")
+                .placeholder("you@example.com")
+                .required(true),
+        );
+        let options = RenderOptions {
+            max_line_width: Some(40),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            form.to_html_with(&options),
+            "<form>\n  <input\n      type=\"email\"\n      name=\"email\"\n      \
+             placeholder=\"you@example.com\"\n      required=true>\n  \n  </input>\n</form>"
+        );
+        assert!(!form.to_html().contains("<input\n"));
+    }
+
+    #[test]
+    fn short_tags_are_not_wrapped() {
+        let link = Node::native("a").href("/").title("Home");
+        let options = RenderOptions {
+            max_line_width: Some(40),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(link.to_html_with(&options), link.to_html());
+    }
+
+    #[test]
+    fn component_nodes_forward_attributes_and_children_to_their_root() {
+        let card = Node::pure(card, "News")
+            .with_attribute("class", "wide")
+            .id("news")
+            .with_child(Node::native("p").with_child(Node::text("Today")));
+
+        let root = crate::diff::resolve(&card);
+        assert_eq!(root.attributes["class"], NodeAttributeValue::from("card wide"));
+        assert_eq!(root.attributes["id"], NodeAttributeValue::from("news"));
+        assert_eq!(root.children.len(), 2);
+        // The node itself only remembers what was added, for `refresh`.
+        assert_eq!(card.attributes["class"], NodeAttributeValue::from("wide"));
+        assert!(card.children.is_empty());
+        assert!(card.to_html().starts_with("<article class=\"card wide\" id=\"news\">"));
+        assert!(card.to_html().contains("  <p>\n    Today\n  </p>\n</article>"));
+    }
+
+    #[derive(Default)]
+    struct Clicks(u32);
+
+    impl Component for Clicks {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            Node::native("button")
+                .with_attribute("class", "counter")
+                .with_child(Node::text_of(self.0))
+        }
+
+        fn update(&mut self, _: ()) -> Option<crate::Effect> {
+            self.0 += 1;
+            None
+        }
+    }
+
+    fn click(node: &mut Node) {
+        if let NodeKind::Custom { component, .. } = &mut node.kind {
+            component.update(());
+        }
+    }
+
+    #[test]
+    fn refresh_renders_components_with_their_current_state() {
+        let mut counter = Node::custom(Box::new(Clicks::default()))
+            .with_attribute("class", "wide")
+            .with_child(Node::text("!"));
+        let texts = |node: &Node| texts(crate::diff::resolve(node));
+        assert_eq!(texts(&counter), ["0", "!"]);
+
+        click(&mut counter);
+        assert_eq!(texts(&counter), ["0", "!"]);
+        counter.refresh();
+        assert_eq!(texts(&counter), ["1", "!"]);
+
+        click(&mut counter);
+        counter.refresh();
+        let root = crate::diff::resolve(&counter);
+        assert_eq!(texts(&counter), ["2", "!"]);
+        assert_eq!(root.attributes["class"], NodeAttributeValue::from("counter wide"));
+    }
+
+    #[test]
+    fn refresh_reaches_nested_components() {
+        let mut page = Node::native("main")
+            .with_child(Node::native("section").with_child(Node::custom(Box::new(Clicks(5)))));
+
+        click(&mut page.children[0].children[0]);
+        page.refresh();
+
+        assert!(page.to_html().contains("<button class=\"counter\">\n      6\n"));
+    }
+
+    #[test]
+    fn refresh_keeps_children_replaced_on_component_nodes() {
+        let mut counter = Node::custom(Box::new(Clicks::default()));
+        counter.replace_children(vec![Node::text("a"), Node::text("b")]);
+        assert_eq!(counter.remove_child(0).unwrap().to_html(), "a");
+
+        click(&mut counter);
+        counter.refresh();
+
+        assert_eq!(texts(crate::diff::resolve(&counter)), ["1", "b"]);
+    }
+
+    #[test]
+    fn children_inserted_into_component_nodes_survive_refresh() {
+        let mut counter = Node::custom(Box::new(Clicks::default()))
+            .with_child(Node::text("b"))
+            .with_child_at(0, Node::text("a"))
+            .with_child_at(usize::MAX, Node::text("c"));
+        counter.insert_child(2, Node::text("!"));
+        assert_eq!(texts(crate::diff::resolve(&counter)), ["0", "a", "!", "b", "c"]);
+
+        click(&mut counter);
+        counter.refresh();
+
+        assert_eq!(texts(crate::diff::resolve(&counter)), ["1", "a", "!", "b", "c"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_attributes_round_trip_through_the_markup() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Props {
+            id: u32,
+            title: String,
+        }
+
+        let props = Props {
+            id: 5,
+            title: "Say \"hi\"".to_string(),
+        };
+        let node = Node::native("div").with_json_of("data-props", &props);
+
+        assert_eq!(
+            node.to_html(),
+            "<div data-props=\"{&quot;id&quot;:5,&quot;title&quot;:&quot;Say \\&quot;hi\\&quot;\
+             &quot;}\">\n\n</div>"
+        );
+        let json = node.attributes["data-props"].as_text();
+        assert_eq!(serde_json::from_str::<Props>(&json).unwrap(), props);
+    }
+
+    fn custom_button(disabled: bool) -> crate::testing::MockElement {
+        let button = Node::native("div")
+            .on_click(Msg::Increment)
+            .button_role()
+            .aria_disabled(disabled);
+        match crate::testing::mount(&button) {
+            crate::testing::MockNode::Element(element) => element,
+            crate::testing::MockNode::Text(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn disabled_custom_button_dispatches_nothing() {
+        let disabled = custom_button(true);
+
+        assert!(disabled.click().is_none());
+        assert!(disabled.press(" ", Modifiers::NONE).is_none());
+        assert!(disabled.press("Enter", Modifiers::NONE).is_none());
+        assert_eq!(disabled.attributes["aria-disabled"], NodeAttributeValue::from("true"));
+        assert!(!disabled.attributes.contains_key("tabindex"));
+
+        let enabled = custom_button(false);
+        assert!(enabled.click().is_some());
+        assert!(!enabled.attributes.contains_key("aria-disabled"));
+        assert_eq!(enabled.attributes["tabindex"], NodeAttributeValue::Number(0));
+    }
+
+    #[test]
+    fn space_and_enter_click_a_button_role() {
+        let button = custom_button(false);
+        let message = |msg: Option<Box<dyn AnyMessage>>| {
+            let msg: Box<dyn std::any::Any> = msg.unwrap();
+            *msg.downcast::<Msg>().unwrap()
+        };
+
+        assert_eq!(message(button.press(" ", Modifiers::NONE)), Msg::Increment);
+        assert_eq!(message(button.press("Enter", Modifiers::NONE)), Msg::Increment);
+        assert!(button.press("a", Modifiers::NONE).is_none());
+        assert_eq!(button.attributes["role"], NodeAttributeValue::from("button"));
+    }
+
+    #[test]
+    fn checkbox_role_only_toggles_on_space() {
+        let checkbox = Node::native("span").on_click(Msg::Increment).checkbox_role(true);
+        let checkbox = crate::testing::mount(&checkbox);
+        let checkbox = checkbox.as_element().unwrap();
+
+        assert!(checkbox.press(" ", Modifiers::NONE).is_some());
+        assert!(checkbox.press("Enter", Modifiers::NONE).is_none());
+        assert_eq!(checkbox.attributes["aria-checked"], NodeAttributeValue::from("true"));
+    }
+
+    #[test]
+    fn attributes_sorted_is_stable_across_calls() {
+        let keys = ["title", "id", "data-z", "aria-label", "class", "data-a", "href"];
+        let link = keys.iter().fold(Node::native("a"), |link, key| link.with_attribute(key, *key));
+        let reversed = keys
+            .iter()
+            .rev()
+            .fold(Node::native("a"), |link, key| link.with_attribute(key, *key));
+        let order = |node: &Node| -> Vec<String> {
+            node.attributes_sorted()
+                .map(|(key, value)| format!("{}={}", key, value.as_text()))
+                .collect()
+        };
+
+        let first = order(&link);
+        assert_eq!(
+            first,
+            [
+                "aria-label=aria-label",
+                "class=class",
+                "data-a=data-a",
+                "data-z=data-z",
+                "href=href",
+                "id=id",
+                "title=title",
+            ]
+        );
+        for _ in 0..10 {
+            assert_eq!(order(&link), first);
+        }
+        assert_eq!(order(&reversed), first);
+    }
+}
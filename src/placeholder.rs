@@ -0,0 +1,125 @@
+//! Ready-made views for content that isn't there yet or at all, so loading
+//! and empty states look the same throughout an app.
+//!
+//! They come without styles, only with classes to hang them on. Setting
+//! `class` with [`Node::with_attribute`] replaces the default one.
+
+use crate::Node;
+
+/// An element that announces "Loading" to screen readers, `<div
+/// class="slick-spinner" role="status">`. Style it into something that
+/// spins.
+pub fn spinner() -> Node {
+    Node::native("div")
+        .with_attribute("class", "slick-spinner")
+        .with_attribute("role", "status")
+        .with_attribute("aria-label", "Loading")
+}
+
+/// Stand-ins for `lines` lines of text, a `<div class="slick-skeleton">`
+/// with a `<div class="slick-skeleton-line">` per line. Screen readers skip
+/// the lines and learn that the content is still loading instead.
+pub fn skeleton(lines: usize) -> Node {
+    (0..lines).fold(
+        Node::native("div")
+            .with_attribute("class", "slick-skeleton")
+            .with_attribute("aria-busy", "true"),
+        |skeleton, _| {
+            skeleton.with_child(
+                Node::native("div")
+                    .with_attribute("class", "slick-skeleton-line")
+                    .with_attribute("aria-hidden", "true"),
+            )
+        },
+    )
+}
+
+/// Says why there is nothing to show, like "No messages yet", in a
+/// `<div class="slick-empty-state">` holding a paragraph with `message`.
+pub fn empty_state(message: &str) -> Node {
+    Node::native("div")
+        .with_attribute("class", "slick-empty-state")
+        .with_child(Node::native("p").with_child(Node::text(message)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NodeAttributeValue, NodeKind};
+
+    fn class(node: &Node) -> &NodeAttributeValue {
+        &node.attributes["class"]
+    }
+
+    #[test]
+    fn spinner_is_a_status() {
+        let spinner = spinner();
+
+        assert!(matches!(&spinner.kind, NodeKind::Native { tag } if tag == "div"));
+        assert_eq!(class(&spinner), &NodeAttributeValue::from("slick-spinner"));
+        assert_eq!(
+            spinner.attributes["role"],
+            NodeAttributeValue::from("status")
+        );
+        assert_eq!(
+            spinner.attributes["aria-label"],
+            NodeAttributeValue::from("Loading")
+        );
+        assert!(spinner.children.is_empty());
+    }
+
+    #[test]
+    fn skeleton_has_a_hidden_line_per_line() {
+        let skeleton = skeleton(3);
+
+        assert_eq!(
+            class(&skeleton),
+            &NodeAttributeValue::from("slick-skeleton")
+        );
+        assert_eq!(
+            skeleton.attributes["aria-busy"],
+            NodeAttributeValue::from("true")
+        );
+        assert_eq!(skeleton.children.len(), 3);
+        for line in &skeleton.children {
+            assert_eq!(
+                class(line),
+                &NodeAttributeValue::from("slick-skeleton-line")
+            );
+            assert_eq!(
+                line.attributes["aria-hidden"],
+                NodeAttributeValue::from("true")
+            );
+        }
+        assert!(super::skeleton(0).children.is_empty());
+    }
+
+    #[test]
+    fn empty_state_holds_the_message() {
+        assert_eq!(
+            empty_state("No messages yet").to_html(),
+            "<div class=\"slick-empty-state\">\n  <p>\n    No messages yet\n  </p>\n</div>"
+        );
+    }
+
+    #[test]
+    fn classes_are_overridable() {
+        let overridden = [
+            spinner().with_attribute("class", "loader"),
+            skeleton(1).with_attribute("class", "loader"),
+            empty_state("Nothing").with_attribute("class", "loader"),
+        ];
+
+        for node in &overridden {
+            assert_eq!(class(node), &NodeAttributeValue::from("loader"));
+            assert!(!node.to_html().contains("class=\"slick-spinner\""));
+            assert!(!node.to_html().contains("class=\"slick-skeleton\""));
+            assert!(!node.to_html().contains("slick-empty-state"));
+        }
+        assert_eq!(
+            overridden[0].attributes["role"],
+            NodeAttributeValue::from("status")
+        );
+        assert_eq!(overridden[1].children.len(), 1);
+    }
+}
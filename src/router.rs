@@ -0,0 +1,692 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+use wasm_bindgen::JsValue;
+use web_sys::{ScrollRestoration, Window};
+
+use crate::{AnyMessage, Node};
+
+pub type Params = HashMap<String, String>;
+/// Query parameters, see [`parse_query`].
+pub type Query = HashMap<String, String>;
+
+enum Segment {
+    Static(String),
+    Param(String),
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+}
+
+/// Decodes `%XX` escapes and `+` as a space. Malformed escapes are kept as
+/// they are.
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Parses the query string of `path`, like `/search?q=rust&page=2`, into the
+/// decoded values by key. A value without `=` is empty and a key that
+/// appears more than once keeps its first value, like
+/// `URLSearchParams.get` does.
+pub fn parse_query(path: &str) -> Query {
+    let mut query = Query::new();
+    let search = match path.split('#').next().unwrap_or_default().split_once('?') {
+        Some((_, search)) => search,
+        None => return query,
+    };
+
+    for pair in search.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        query.entry(decode(key)).or_insert_with(|| decode(value));
+    }
+
+    query
+}
+
+/// Everything a route view gets to see about the current match.
+pub struct Match<'a> {
+    pub path: &'a str,
+    pub params: &'a Params,
+    /// The query parameters of `path`.
+    pub query: &'a Query,
+    /// The rendered view of the matched child route, if any. Layouts place
+    /// this wherever their sub-page should appear.
+    pub child: Option<Node>,
+}
+
+type View<M> = Box<dyn Fn(&M, Match) -> Node>;
+type Fallback<M> = Box<dyn Fn(&M, &str) -> Node>;
+
+pub struct Route<M = ()> {
+    segments: Vec<Segment>,
+    view: View<M>,
+    children: Vec<Route<M>>,
+}
+
+impl<M> Route<M> {
+    /// `path` is relative to the parent route. Segments starting with `:` are
+    /// captured into [`Match::params`].
+    pub fn new(path: &str, view: impl Fn(&M, Match) -> Node + 'static) -> Self {
+        Self {
+            segments: segments(path)
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => Segment::Param(name.to_string()),
+                    None => Segment::Static(segment.to_string()),
+                })
+                .collect(),
+            view: Box::new(view),
+            children: vec![],
+        }
+    }
+
+    pub fn with_child(mut self, child: Route<M>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns the chain of matched routes, outermost first.
+    fn resolve<'r>(&'r self, path: &[&str], params: &mut Params) -> Option<Vec<&'r Route<M>>> {
+        if path.len() < self.segments.len() {
+            return None;
+        }
+
+        let mut captured = vec![];
+
+        for (segment, value) in self.segments.iter().zip(path) {
+            match segment {
+                Segment::Static(x) if x == value => {}
+                Segment::Static(_) => return None,
+                Segment::Param(name) => captured.push((name.clone(), value.to_string())),
+            }
+        }
+
+        let rest = &path[self.segments.len()..];
+
+        let chain = if rest.is_empty() {
+            Some(vec![self])
+        } else {
+            self.children.iter().find_map(|child| {
+                let mut chain = child.resolve(rest, params)?;
+                chain.insert(0, self);
+                Some(chain)
+            })
+        };
+
+        if chain.is_some() {
+            params.extend(captured);
+        }
+
+        chain
+    }
+}
+
+/// Maps paths to views.
+///
+/// Routes are tried in the order they were added. A route with children
+/// matches its own path exactly (rendering without a child) or any path where
+/// one of its children matches the remainder. If none of the children match,
+/// the parent does not match either and matching falls through to the next
+/// sibling route.
+pub struct Router<M = ()> {
+    routes: Vec<Route<M>>,
+    fallback: Option<Fallback<M>>,
+}
+
+impl<M> Default for Router<M> {
+    fn default() -> Self {
+        Self {
+            routes: vec![],
+            fallback: None,
+        }
+    }
+}
+
+impl<M> Router<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_route(mut self, route: Route<M>) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Renders `view` whenever no route matches. It receives the path that
+    /// failed to match.
+    pub fn with_fallback(mut self, view: impl Fn(&M, &str) -> Node + 'static) -> Self {
+        self.fallback = Some(Box::new(view));
+        self
+    }
+
+    /// Renders the routes matching `path`, innermost first, handing every
+    /// rendered view to its parent as [`Match::child`].
+    ///
+    /// Returns `None` only if nothing matches and there is no fallback.
+    pub fn view(&self, model: &M, path: &str) -> Option<Node> {
+        let segments: Vec<&str> = segments(path).collect();
+        let mut params = Params::new();
+        let query = parse_query(path);
+
+        match self
+            .routes
+            .iter()
+            .find_map(|route| route.resolve(&segments, &mut params))
+        {
+            Some(chain) => chain.into_iter().rev().fold(None, |child, route| {
+                Some((route.view)(
+                    model,
+                    Match {
+                        path,
+                        params: &params,
+                        query: &query,
+                        child,
+                    },
+                ))
+            }),
+            None => self.fallback.as_ref().map(|fallback| fallback(model, path)),
+        }
+    }
+}
+
+struct ScrollState {
+    enabled: bool,
+    /// Key of the history entry currently shown.
+    current: Option<f64>,
+    /// The highest key given out or found in the history, so every new
+    /// entry gets a key of its own, even several pushed at once.
+    last_key: f64,
+    /// Path of the history entry currently shown, to go back to when a
+    /// [`Component::before_navigate`](crate::Component::before_navigate)
+    /// blocks leaving it.
+    path: String,
+    /// The id of the element to scroll to once it is rendered, from the
+    /// fragment of the URL.
+    anchor: Option<String>,
+    positions: HashMap<u64, (f64, f64)>,
+}
+
+impl ScrollState {
+    fn new() -> Self {
+        Self {
+            enabled: true,
+            current: None,
+            last_key: 0.0,
+            path: String::new(),
+            anchor: None,
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Moves to the entry with `key` and `path` after a `popstate` event,
+    /// remembering that the entry that was left was scrolled to `left_at`.
+    /// Returns the position to restore, if there is one.
+    ///
+    /// Following an in-page link like `<a href="#id">` pops too, to an entry
+    /// without a key or with just another fragment. That is no navigation:
+    /// the key stays and the page scrolls to the element instead.
+    fn pop(
+        &mut self,
+        key: Option<f64>,
+        path: String,
+        fragment: &str,
+        left_at: (f64, f64),
+    ) -> Option<(f64, f64)> {
+        if let Some(current) = self.current {
+            self.positions.insert(current.to_bits(), left_at);
+        }
+        if let Some(key) = key {
+            self.seen(key);
+        }
+
+        if key.is_none() || path == self.path {
+            self.current = key.or(self.current);
+            self.path = path;
+            self.anchor = anchor(fragment);
+            return None;
+        }
+
+        self.current = key;
+        self.path = path;
+        self.anchor = None;
+
+        let position = key.and_then(|key| self.positions.get(&key.to_bits()).copied());
+        position.filter(|_| self.enabled)
+    }
+
+    /// A key for a new history entry, higher than any before.
+    fn next_key(&mut self) -> f64 {
+        self.last_key += 1.0;
+        self.last_key
+    }
+
+    /// Notes that an entry with `key` exists, like one from before a reload,
+    /// so later keys don't collide with it.
+    fn seen(&mut self, key: f64) {
+        self.last_key = self.last_key.max(key);
+    }
+}
+
+thread_local! {
+    static SCROLL: RefCell<ScrollState> = RefCell::new(ScrollState::new());
+}
+
+/// Where the router keeps the path in the URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// In the path itself, like `/about`. The server has to answer every path
+    /// with the app, or reloading anything but `/` fails.
+    History,
+    /// In the fragment, like `/#/about`, which never reaches the server. For
+    /// static hosts that can't rewrite paths to `index.html`.
+    Hash,
+}
+
+thread_local! {
+    static MODE: Cell<Mode> = const { Cell::new(Mode::History) };
+}
+
+/// Switches between history and hash routing, [`Mode::History`] by default.
+/// Call it before [`run`](crate::run). [`current_path`] and
+/// [`Effect::Navigate`](crate::Effect::Navigate) follow the mode, so routes
+/// and paths stay the same in both.
+pub fn set_mode(mode: Mode) {
+    MODE.with(|current| current.set(mode));
+}
+
+pub(crate) fn mode() -> Mode {
+    MODE.with(Cell::get)
+}
+
+/// Path of the current browser location, including the query string if
+/// there is one.
+pub fn current_path() -> String {
+    let location = match web_sys::window() {
+        Some(window) => window.location(),
+        None => return "/".to_string(),
+    };
+
+    match mode() {
+        Mode::History => {
+            let path = location.pathname().unwrap_or_else(|_| "/".to_string());
+            path + &location.search().unwrap_or_default()
+        }
+        Mode::Hash => {
+            let hash = location.hash().unwrap_or_default();
+            let path = hash.trim_start_matches('#');
+            if path.starts_with('/') {
+                path.to_string()
+            } else {
+                format!("/{}", path)
+            }
+        }
+    }
+}
+
+/// The parts of the current browser location, see
+/// [`Effect::GetLocation`](crate::Effect::GetLocation). Follows the
+/// [`Mode`] like [`current_path`] does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    /// The path without the query and the fragment, like `/search`.
+    pub path: String,
+    /// The decoded query parameters, see [`parse_query`].
+    pub query: Query,
+    /// The fragment without the `#`, empty if there is none. Always empty in
+    /// hash mode, where the fragment holds the path.
+    pub hash: String,
+}
+
+pub(crate) type OnLocation = Box<dyn Fn(Location) -> Box<dyn AnyMessage>>;
+
+pub(crate) fn location() -> Location {
+    let hash = match mode() {
+        Mode::History => web_sys::window()
+            .and_then(|window| window.location().hash().ok())
+            .unwrap_or_default(),
+        Mode::Hash => String::new(),
+    };
+    let path = current_path();
+
+    Location {
+        query: parse_query(&path),
+        path: path
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        hash: hash.trim_start_matches('#').to_string(),
+    }
+}
+
+/// Scroll restoration is on by default: navigating to a new path scrolls to
+/// the top and going back or forward restores the position the entry was
+/// left at. Positions are only remembered for the lifetime of the page.
+pub fn set_scroll_restoration(enabled: bool) {
+    SCROLL.with(|scroll| scroll.borrow_mut().enabled = enabled);
+
+    if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+        let _ = history.set_scroll_restoration(if enabled {
+            ScrollRestoration::Manual
+        } else {
+            ScrollRestoration::Auto
+        });
+    }
+}
+
+fn scroll_position(window: &Window) -> (f64, f64) {
+    (
+        window.scroll_x().unwrap_or_default(),
+        window.scroll_y().unwrap_or_default(),
+    )
+}
+
+/// Remembers where the current entry was scrolled to before it is left.
+fn save_scroll(window: &Window, scroll: &mut ScrollState) {
+    if let Some(key) = scroll.current {
+        scroll
+            .positions
+            .insert(key.to_bits(), scroll_position(window));
+    }
+}
+
+/// Called once when the runtime starts, so the initial entry gets a key too.
+pub(crate) fn init_history() {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+
+    SCROLL.with(|scroll| {
+        let mut scroll = scroll.borrow_mut();
+        let state = window.history().and_then(|history| history.state());
+        let key = match state.ok().and_then(|state| state.as_f64()) {
+            Some(key) => {
+                scroll.seen(key);
+                key
+            }
+            None => {
+                let key = scroll.next_key();
+                if let Ok(history) = window.history() {
+                    let _ = history.replace_state(&JsValue::from_f64(key), "");
+                }
+                key
+            }
+        };
+
+        scroll.current = Some(key);
+        scroll.path = current_path();
+        scroll.anchor = window.location().hash().ok().as_deref().and_then(anchor);
+
+        if scroll.enabled {
+            if let Ok(history) = window.history() {
+                let _ = history.set_scroll_restoration(ScrollRestoration::Manual);
+            }
+        }
+    });
+}
+
+/// Pushes a new history entry. The runtime re-renders afterwards.
+pub(crate) fn push(path: &str) {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+
+    SCROLL.with(|scroll| {
+        let mut scroll = scroll.borrow_mut();
+        let key = scroll.next_key();
+
+        save_scroll(&window, &mut scroll);
+
+        push_url(&window, key, path);
+
+        scroll.current = Some(key);
+        let (path, fragment) = path.split_once('#').unwrap_or((path, ""));
+        scroll.path = path.to_string();
+        scroll.anchor = anchor(fragment);
+    });
+}
+
+/// The URL `path` is shown as in the address bar.
+fn url(path: &str) -> String {
+    match mode() {
+        Mode::History => path.to_string(),
+        Mode::Hash => format!("#{}", path),
+    }
+}
+
+fn push_url(window: &Window, key: f64, path: &str) {
+    if let Ok(history) = window.history() {
+        let _ = history.push_state_with_url(&JsValue::from_f64(key), "", Some(&url(path)));
+    }
+}
+
+/// Changes the URL of the current history entry to `path`, keeping its
+/// scroll position.
+pub(crate) fn replace(path: &str) {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+
+    SCROLL.with(|scroll| {
+        let mut scroll = scroll.borrow_mut();
+        let key = scroll.current.unwrap_or_default();
+
+        if let Ok(history) = window.history() {
+            let _ = history.replace_state_with_url(&JsValue::from_f64(key), "", Some(&url(path)));
+        }
+
+        let (path, _) = path.split_once('#').unwrap_or((path, ""));
+        scroll.path = path.to_string();
+    });
+}
+
+/// The path of the entry that is shown, which differs from [`current_path`]
+/// while a navigation of the browser waits for approval.
+pub(crate) fn shown_path() -> String {
+    SCROLL.with(|scroll| scroll.borrow().path.clone())
+}
+
+/// Whether the browser went somewhere else than the path that is shown,
+/// like after going back or following a hash link.
+pub(crate) fn location_changed() -> bool {
+    SCROLL.with(|scroll| scroll.borrow().path != current_path())
+}
+
+/// Puts the path that is shown back into the address bar after the browser
+/// left it on its own and the component blocked that. The entry that was left
+/// to stays in the history.
+pub(crate) fn revert() {
+    if let Some(window) = web_sys::window() {
+        SCROLL.with(|scroll| {
+            let scroll = scroll.borrow();
+            push_url(&window, scroll.current.unwrap_or_default(), &scroll.path);
+        });
+    }
+}
+
+/// Called when the browser changed the hash without a `popstate` event.
+pub(crate) fn hash_changed() {
+    SCROLL.with(|scroll| scroll.borrow_mut().path = current_path());
+}
+
+/// Called after a `popstate` event with the state of the entry that is now
+/// active. Returns the position to restore once the new view is rendered.
+pub(crate) fn pop(state: JsValue) -> Option<(f64, f64)> {
+    let window = web_sys::window()?;
+    let fragment = window.location().hash().unwrap_or_default();
+
+    SCROLL.with(|scroll| {
+        scroll.borrow_mut().pop(
+            state.as_f64(),
+            current_path(),
+            &fragment,
+            scroll_position(&window),
+        )
+    })
+}
+
+/// The element id a fragment like `#section-3` refers to. In hash mode the
+/// fragment is the path instead.
+fn anchor(fragment: &str) -> Option<String> {
+    let id = decode(fragment.trim_start_matches('#'));
+    (mode() == Mode::History && !id.is_empty()).then_some(id)
+}
+
+/// Entries pushed for `path` start at the top of the page, or at the element
+/// its fragment refers to.
+pub(crate) fn scroll_to_top(path: &str) {
+    let enabled = SCROLL.with(|scroll| scroll.borrow().enabled);
+    let fragment = path.split_once('#').map(|(_, fragment)| fragment);
+
+    // The render before already scrolled to the anchor, or will once the
+    // element exists.
+    if enabled && fragment.and_then(anchor).is_none() {
+        scroll_to((0.0, 0.0));
+    }
+}
+
+/// Called after every render. Scrolls to the element the fragment of the
+/// URL refers to once it exists, so content rendered later is found too.
+pub(crate) fn scroll_to_anchor() {
+    let id = match SCROLL.with(|scroll| scroll.borrow().anchor.clone()) {
+        Some(id) => id,
+        None => return,
+    };
+    let document = web_sys::window().and_then(|window| window.document());
+
+    if let Some(element) = document.and_then(|document| document.get_element_by_id(&id)) {
+        element.scroll_into_view();
+        SCROLL.with(|scroll| scroll.borrow_mut().anchor = None);
+    }
+}
+
+pub(crate) fn scroll_to((x, y): (f64, f64)) {
+    if let Some(window) = web_sys::window() {
+        window.scroll_to_with_x_and_y(x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router() -> Router {
+        Router::new()
+            .with_route(Route::new("/", |_, _| Node::text("home")))
+            .with_route(Route::new("/users/:id", |_, m| Node::text(&m.params["id"])))
+    }
+
+    #[test]
+    fn unknown_path_renders_fallback() {
+        let router = router().with_fallback(|_, path| Node::text(format!("{} not found", path)));
+
+        let view = router.view(&(), "/nope?page=2").unwrap();
+        assert_eq!(view.to_html(), "/nope?page=2 not found");
+    }
+
+    #[test]
+    fn matching_path_skips_fallback() {
+        let router = router().with_fallback(|_, _| Node::text("not found"));
+
+        assert_eq!(router.view(&(), "/users/7").unwrap().to_html(), "7");
+        assert_eq!(
+            router.view(&(), "/users/7/posts").unwrap().to_html(),
+            "not found"
+        );
+    }
+
+    fn visited(path: &str, key: f64) -> ScrollState {
+        let mut scroll = ScrollState::new();
+        scroll.current = Some(key);
+        scroll.path = path.to_string();
+        scroll
+    }
+
+    #[test]
+    fn back_restores_saved_position() {
+        let mut scroll = visited("/a", 1.0);
+        scroll.positions.insert(2f64.to_bits(), (0.0, 300.0));
+
+        let position = scroll.pop(Some(2.0), "/b".to_string(), "", (0.0, 50.0));
+
+        assert_eq!(position, Some((0.0, 300.0)));
+        assert_eq!(scroll.current, Some(2.0));
+        assert_eq!(scroll.positions[&1f64.to_bits()], (0.0, 50.0));
+    }
+
+    #[test]
+    fn unknown_key_keeps_position() {
+        let mut scroll = visited("/a", 1.0);
+
+        assert_eq!(
+            scroll.pop(Some(2.0), "/b".to_string(), "", (0.0, 50.0)),
+            None
+        );
+        assert_eq!(scroll.current, Some(2.0));
+    }
+
+    #[test]
+    fn hash_link_scrolls_to_anchor() {
+        let mut scroll = visited("/a", 1.0);
+
+        assert_eq!(
+            scroll.pop(None, "/a".to_string(), "#details", (0.0, 50.0)),
+            None
+        );
+        assert_eq!(scroll.current, Some(1.0));
+        assert_eq!(scroll.path, "/a");
+        assert_eq!(scroll.anchor.as_deref(), Some("details"));
+    }
+
+    #[test]
+    fn unknown_path_without_fallback_renders_nothing() {
+        assert!(router().view(&(), "/nope").is_none());
+    }
+
+    #[test]
+    fn entries_pushed_at_once_get_their_own_keys() {
+        let mut scroll = ScrollState::new();
+        let first = scroll.next_key();
+        let second = scroll.next_key();
+        assert!(second > first);
+
+        // Entries from before a reload keep their keys.
+        scroll.seen(10.0);
+        assert_eq!(scroll.next_key(), 11.0);
+
+        scroll.current = Some(11.0);
+        scroll.pop(Some(20.0), "/b".to_string(), "", (0.0, 0.0));
+        assert_eq!(scroll.next_key(), 21.0);
+    }
+}
@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use crate::{AnyMessage, Modifiers, Node};
+
+/// Adds `items` to `container` as a group navigated with the keyboard and a
+/// roving tabindex: only the item at `active` can be reached with Tab, Down
+/// and Up go to the next and previous item, wrapping around, Home and End to
+/// the first and last. `on_move` receives the index to make active, focus
+/// follows once it is rendered.
+///
+/// Roles are up to the caller. Give `container` the `menu` role and the
+/// items `menuitem`, or `listbox` and `option`, where the active option also
+/// needs `aria-selected`.
+pub fn roving<M: AnyMessage>(
+    container: Node,
+    items: Vec<Node>,
+    active: usize,
+    on_move: impl Fn(usize) -> M + 'static,
+) -> Node {
+    roving_along(container, items, active, on_move, ("ArrowDown", "ArrowUp"))
+}
+
+/// Like [`roving`], with `keys` going to the next and previous item.
+pub(crate) fn roving_along<M: AnyMessage>(
+    container: Node,
+    items: Vec<Node>,
+    active: usize,
+    on_move: impl Fn(usize) -> M + 'static,
+    (next, previous): (&'static str, &'static str),
+) -> Node {
+    let count = items.len();
+    let active = active.min(count.saturating_sub(1));
+
+    let container = items
+        .into_iter()
+        .enumerate()
+        .fold(container, |container, (i, item)| {
+            container.with_child(if i == active {
+                item.with_attribute("tabindex", 0).with_roving_focus()
+            } else {
+                item.with_attribute("tabindex", -1)
+            })
+        });
+
+    if count == 0 {
+        return container;
+    }
+
+    let on_move = Rc::new(on_move);
+    let keys = [
+        (next, (active + 1) % count),
+        (previous, (active + count - 1) % count),
+        ("Home", 0),
+        ("End", count - 1),
+    ];
+
+    keys.iter().fold(container, |container, &(key, index)| {
+        let on_move = on_move.clone();
+        container.on_key(key, Modifiers::NONE, move || on_move(index))
+    })
+}
@@ -0,0 +1,1108 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
+    marker::PhantomData,
+    rc::{Rc, Weak},
+};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{Document, Element, HtmlElement, PopStateEvent, ShadowRootInit, ShadowRootMode};
+
+use crate::{
+    aria::{self, Politeness},
+    clipboard,
+    css::{self, ComponentStyles},
+    diff::diff,
+    dom::{Dispatch, Mounted},
+    file,
+    focus::{self, FocusTrap},
+    head,
+    hooks::{self, Hooks, LocalUpdate},
+    http::{self, Retry},
+    measure, media, router,
+    subscription::Subscriptions,
+    unload::UnloadGuard,
+    AnyMessage, Component, Effect, Node,
+};
+
+struct State<C> {
+    component: C,
+    /// The element or shadow root the view is rendered into.
+    root: web_sys::Node,
+    /// The last rendered view and the DOM created for it.
+    rendered: Option<(Node, Mounted)>,
+    /// Nodes removed by the previous render. They are kept alive for one
+    /// more render because the event that caused the re-render may still be
+    /// running one of their listeners.
+    removed: Vec<Mounted>,
+    /// The trap of the rendered `with_focus_trap` node.
+    focus_trap: Option<FocusTrap>,
+    /// Present while [`Component::unsaved_changes`] is true.
+    unload_guard: Option<UnloadGuard>,
+    /// The values of the `use_state` calls in the view.
+    hooks: Hooks,
+    /// What [`Component::subscriptions`] returned after the last render.
+    subscriptions: Subscriptions,
+    /// The [`Component::css`] added to the document so far.
+    styles: ComponentStyles,
+}
+
+type Timeout = Closure<dyn FnMut()>;
+
+/// The effect an [`Effect::then`] continues with, queued as a message once
+/// the result it waited for arrived, and where its own messages go.
+struct Chained(Effect, Dispatch);
+
+impl std::fmt::Debug for Chained {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Chained")
+    }
+}
+
+struct Runtime<C> {
+    document: Document,
+    state: RefCell<State<C>>,
+    queue: RefCell<VecDeque<Box<dyn AnyMessage>>>,
+    dispatch: RefCell<Option<Dispatch>>,
+    /// Scheduled [`Effect::Delay`]s by their timeout handle.
+    delays: Rc<RefCell<HashMap<i32, Timeout>>>,
+    batch_mode: BatchMode,
+    /// The handle of the requested animation frame, if there is one.
+    frame: Cell<Option<i32>>,
+    on_frame: RefCell<Option<Timeout>>,
+}
+
+/// When the runtime applies dispatched messages, see [`RuntimeConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchMode {
+    /// Every message is applied and rendered right when it is dispatched,
+    /// like for simple apps and tests that check the DOM right after an
+    /// event.
+    Immediate,
+    /// Messages are queued and applied together in the next animation frame,
+    /// rendering once, however many came in since the last one. Browsers
+    /// pause animation frames in background tabs, so messages wait until the
+    /// tab is shown again.
+    #[default]
+    AnimationFrame,
+}
+
+/// How a runtime started with [`run_with_config`] behaves.
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeConfig {
+    pub batch_mode: BatchMode,
+}
+
+/// Lets [`request_render`] reach runtimes of any component type.
+trait Render {
+    fn render(&self);
+}
+
+thread_local! {
+    /// Every mounted runtime. Unmounted ones are pruned on the next
+    /// [`request_render`].
+    static RENDERERS: RefCell<Vec<Weak<dyn Render>>> = RefCell::new(vec![]);
+}
+
+/// Re-renders all mounted components, for state that changes outside of
+/// `update`, like a finished [`lazy`](crate::lazy) load.
+pub fn request_render() {
+    let renderers = RENDERERS.with(|renderers| {
+        let mut renderers = renderers.borrow_mut();
+        renderers.retain(|renderer| renderer.strong_count() > 0);
+        renderers.clone()
+    });
+
+    for renderer in renderers.iter().filter_map(Weak::upgrade) {
+        renderer.render();
+    }
+}
+
+/// Feeds messages into a running component from outside of it, e.g. from
+/// the callback of a JavaScript library.
+///
+/// The handle only refers to the runtime weakly: once the component is
+/// unmounted, dispatching does nothing. Like everything else in slick it is
+/// tied to the thread it was created on, which in the browser is the only
+/// one anyway.
+pub struct Dispatcher<M> {
+    dispatch: Dispatch,
+    message: PhantomData<fn(M)>,
+}
+
+impl<M> Clone for Dispatcher<M> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatch: self.dispatch.clone(),
+            message: PhantomData,
+        }
+    }
+}
+
+impl<M: AnyMessage> Dispatcher<M> {
+    /// Runs `msg` through `update` and re-renders, just like a message from
+    /// a handler.
+    pub fn dispatch(&self, msg: M) {
+        (self.dispatch)(Box::new(msg));
+    }
+
+    /// Creates a JavaScript function that dispatches the message `to_message`
+    /// turns its first argument into. Values it returns `None` for are
+    /// ignored.
+    ///
+    /// The function is owned by JavaScript and collected once nothing refers
+    /// to it anymore.
+    pub fn to_js_function(
+        &self,
+        to_message: impl Fn(JsValue) -> Option<M> + 'static,
+    ) -> js_sys::Function {
+        let dispatcher = self.clone();
+        Closure::wrap(Box::new(move |value: JsValue| {
+            if let Some(msg) = to_message(value) {
+                dispatcher.dispatch(msg);
+            }
+        }) as Box<dyn FnMut(JsValue)>)
+        .into_js_value()
+        .unchecked_into()
+    }
+}
+
+/// Mounts `component` into the first element matching `selector` and keeps it
+/// rendered as messages come in.
+///
+/// Re-renders patch the DOM in place, only elements whose tag changed are
+/// created anew. As long as `view` returns the same root tag the root element
+/// stays the same, with its scroll position and focus.
+///
+/// Messages are applied in the next animation frame, rendering once for all
+/// of them, see [`run_with_config`] to render right away instead.
+///
+/// The returned [`Dispatcher`] lets code outside of the component send it
+/// messages. It can be ignored otherwise.
+pub fn run<C>(component: C, selector: &str) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    start(component, selector, false, RuntimeConfig::default())
+}
+
+/// Like [`run`], but with `config` instead of the defaults, like
+/// [`BatchMode::Immediate`] rendering.
+pub fn run_with_config<C>(
+    component: C,
+    selector: &str,
+    config: RuntimeConfig,
+) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    start(component, selector, false, config)
+}
+
+/// Like [`run`], but renders into a shadow root attached to the element, so
+/// the page's styles don't apply to the component and `<style>` elements in
+/// its view don't apply to the page.
+///
+/// Handlers keep working as usual, they are attached to the elements inside
+/// the shadow root and see the events before they are retargeted to the host.
+pub fn run_in_shadow<C>(component: C, selector: &str) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    start(component, selector, true, RuntimeConfig::default())
+}
+
+/// Like [`run`], but mounts into `target`, an element the caller already
+/// holds, like one a larger app created for slick. Every call starts an
+/// independent runtime, so several islands can live on one page, each with
+/// its own component, queue and renders. `target` doesn't have to be in the
+/// document yet, it is rendered into right away either way.
+pub fn mount_into<C>(target: &Element, component: C) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    start_in(component, target.clone().into(), RuntimeConfig::default())
+}
+
+/// Attaches an open shadow root to `element`, or returns the one it already
+/// has.
+pub(crate) fn shadow_root(element: &Element) -> web_sys::Node {
+    match element.shadow_root() {
+        Some(root) => root.into(),
+        None => element
+            .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+            .expect("the element can't have a shadow root")
+            .into(),
+    }
+}
+
+fn start<C>(
+    component: C,
+    selector: &str,
+    shadow: bool,
+    config: RuntimeConfig,
+) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    let window = web_sys::window().expect("no global `window` exists");
+    let document = window.document().expect("should have a document on window");
+    let root = document
+        .query_selector(selector)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| panic!("no element matches `{}`", selector));
+    let root = if shadow {
+        shadow_root(&root)
+    } else {
+        root.into()
+    };
+
+    start_in(component, root, config)
+}
+
+fn start_in<C>(component: C, root: web_sys::Node, config: RuntimeConfig) -> Dispatcher<C::Message>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    let window = web_sys::window().expect("no global `window` exists");
+    let runtime = mount(component, root, config);
+
+    router::init_history();
+
+    let on_pop_state = {
+        let runtime = runtime.clone();
+        Closure::wrap(Box::new(move |event: PopStateEvent| {
+            if !runtime.allow_history_change() {
+                return;
+            }
+            let position = router::pop(event.state());
+            runtime.render();
+            if let Some(position) = position {
+                router::scroll_to(position);
+            }
+        }) as Box<dyn FnMut(PopStateEvent)>)
+    };
+    window
+        .add_event_listener_with_callback("popstate", on_pop_state.as_ref().unchecked_ref())
+        .unwrap();
+    // The runtime lives for as long as the page does.
+    on_pop_state.forget();
+
+    // Browsers fire `popstate` for changes of the hash too, but not all of
+    // them. Rendering again when both fire patches nothing.
+    if router::mode() == router::Mode::Hash {
+        let on_hash_change = {
+            let runtime = runtime.clone();
+            Closure::wrap(Box::new(move || {
+                if runtime.allow_history_change() {
+                    router::hash_changed();
+                    runtime.render();
+                }
+            }) as Box<dyn FnMut()>)
+        };
+        window
+            .add_event_listener_with_callback("hashchange", on_hash_change.as_ref().unchecked_ref())
+            .unwrap();
+        on_hash_change.forget();
+    }
+
+    runtime.render();
+
+    Dispatcher {
+        dispatch: runtime.dispatcher(),
+        message: PhantomData,
+    }
+}
+
+/// A component rendered into an element it doesn't own, like a custom
+/// element. Unlike [`run`] it leaves the browser history alone. Dropping it
+/// unmounts the component and empties the element.
+pub(crate) struct Instance<C>(Rc<Runtime<C>>)
+where
+    C: Component + 'static,
+    C::Message: 'static;
+
+impl<C> Instance<C>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    pub(crate) fn new(component: C, root: web_sys::Node) -> Self {
+        let runtime = mount(component, root, RuntimeConfig::default());
+        runtime.render();
+        Self(runtime)
+    }
+
+    pub(crate) fn dispatch(&self, msg: Box<dyn AnyMessage>) {
+        self.0.dispatch(msg);
+    }
+}
+
+impl<C> Drop for Instance<C>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            for (handle, _) in self.0.delays.borrow_mut().drain() {
+                window.clear_timeout_with_handle(handle);
+            }
+            if let Some(handle) = self.0.frame.take() {
+                let _ = window.cancel_animation_frame(handle);
+            }
+        }
+
+        // Dropped from inside a listener of the instance the state is still
+        // borrowed, the runtime goes away after the listener returns anyway.
+        if let Ok(mut state) = self.0.state.try_borrow_mut() {
+            state.rendered = None;
+            state.removed.clear();
+            state.focus_trap = None;
+            state.unload_guard = None;
+            state.subscriptions = Subscriptions::default();
+            state.root.set_text_content(None);
+        }
+    }
+}
+
+fn mount<C>(component: C, root: web_sys::Node, config: RuntimeConfig) -> Rc<Runtime<C>>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    let document = root
+        .owner_document()
+        .expect("the root is part of a document");
+
+    let runtime = Rc::new(Runtime {
+        document,
+        state: RefCell::new(State {
+            component,
+            root,
+            rendered: None,
+            removed: vec![],
+            focus_trap: None,
+            unload_guard: None,
+            hooks: Hooks::default(),
+            subscriptions: Subscriptions::default(),
+            styles: ComponentStyles::default(),
+        }),
+        queue: RefCell::new(VecDeque::new()),
+        dispatch: RefCell::new(None),
+        delays: Rc::new(RefCell::new(HashMap::new())),
+        batch_mode: config.batch_mode,
+        frame: Cell::new(None),
+        on_frame: RefCell::new(None),
+    });
+
+    let weak: Weak<Runtime<C>> = Rc::downgrade(&runtime);
+    *runtime.dispatch.borrow_mut() = Some(Rc::new({
+        let weak = weak.clone();
+        move |msg| {
+            if let Some(runtime) = weak.upgrade() {
+                runtime.dispatch(msg);
+            }
+        }
+    }));
+    // Created once and reused for every frame, it can't be dropped while it
+    // runs.
+    *runtime.on_frame.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+        if let Some(runtime) = weak.upgrade() {
+            runtime.frame.set(None);
+            runtime.flush();
+        }
+    }) as Box<dyn FnMut()>));
+
+    let renderer: Rc<dyn Render> = runtime.clone();
+    RENDERERS.with(|renderers| renderers.borrow_mut().push(Rc::downgrade(&renderer)));
+
+    runtime
+}
+
+impl<C> Render for Runtime<C>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    fn render(&self) {
+        Runtime::render(self);
+    }
+}
+
+impl<C> Runtime<C>
+where
+    C: Component + 'static,
+    C::Message: 'static,
+{
+    fn dispatcher(&self) -> Dispatch {
+        self.dispatch.borrow().clone().unwrap()
+    }
+
+    fn dispatch(&self, msg: Box<dyn AnyMessage>) {
+        let flushing = self.state.try_borrow().is_err();
+        match enqueue(&self.queue, msg, self.batch_mode, flushing) {
+            Schedule::Now => self.flush(),
+            Schedule::NextFrame => self.request_frame(),
+            Schedule::Running => {}
+        }
+    }
+
+    /// Has the queue worked off in the next animation frame, unless that is
+    /// already requested.
+    fn request_frame(&self) {
+        if self.frame.get().is_some() {
+            return;
+        }
+        let on_frame = self.on_frame.borrow();
+        let (window, on_frame) = match (web_sys::window(), on_frame.as_ref()) {
+            (Some(window), Some(on_frame)) => (window, on_frame),
+            _ => return,
+        };
+        match window.request_animation_frame(on_frame.as_ref().unchecked_ref()) {
+            Ok(handle) => self.frame.set(Some(handle)),
+            Err(_) => self.flush(),
+        }
+    }
+
+    /// Applies every queued message, renders and runs the effects.
+    fn flush(&self) {
+        // Messages dispatched while an update is running are picked up by
+        // the loop in `work_off` once it gets to them.
+        let mut state = match self.state.try_borrow_mut() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+
+        work_off(
+            &mut *state,
+            |state| &mut state.component,
+            &self.queue,
+            &self.dispatcher(),
+            |state| self.render_into(state),
+            |state, effect, dispatch| self.run_effect_with(state, effect, Retry::NEVER, dispatch),
+        );
+    }
+
+    /// Runs `effect`, handing the messages it produces to `dispatch`.
+    fn run_effect_with(
+        &self,
+        state: &mut State<C>,
+        effect: Effect,
+        retry: Retry,
+        dispatch: Dispatch,
+    ) {
+        match effect {
+            Effect::Navigate(path)
+                if !state
+                    .component
+                    .before_navigate(&router::shown_path(), &path) =>
+            {
+                self.render_into(state)
+            }
+            Effect::Navigate(path) | Effect::ForceNavigate(path) => {
+                router::push(&path);
+                self.render_into(state);
+                router::scroll_to_top(&path);
+            }
+            Effect::ReplaceUrl(path) => router::replace(&path),
+            Effect::GetLocation { on_value } => dispatch(on_value(router::location())),
+            Effect::Announce(text) => aria::announce(&text, Politeness::Polite),
+            Effect::AnnounceAssertive(text) => aria::announce(&text, Politeness::Assertive),
+            Effect::ReadFileText { file, on_load } => {
+                file::read_text(file, on_load, dispatch.clone())
+            }
+            Effect::ReadFileBytes { file, on_load } => {
+                file::read_bytes(file, on_load, dispatch.clone())
+            }
+            Effect::Upload {
+                url,
+                fields,
+                files,
+                on_progress,
+                on_uploaded,
+            } => http::upload(
+                url,
+                fields,
+                files,
+                on_progress,
+                on_uploaded,
+                retry,
+                dispatch.clone(),
+            ),
+            Effect::CopyToClipboard(text, on_copied) => {
+                clipboard::copy(text, on_copied, dispatch.clone())
+            }
+            Effect::Delay { ms, msg } => self.delay(ms, msg, dispatch),
+            Effect::MediaPlay(selector) => media::play(&state.root, &selector),
+            Effect::MediaPause(selector) => media::pause(&state.root, &selector),
+            Effect::MediaSeek { selector, seconds } => media::seek(&state.root, &selector, seconds),
+            Effect::Measure { selector, on_rect } => {
+                measure::measure(&state.root, &selector, on_rect, dispatch.clone())
+            }
+            Effect::SetMeta { key, content } => head::set_meta(&key, &content),
+            Effect::SetTitle(title) => head::set_title(&title),
+            Effect::SetCssVar { name, value } => css::set_var(&name, value.as_deref()),
+            Effect::Print => {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.print();
+                }
+            }
+            Effect::Command(future) => {
+                wasm_bindgen_futures::spawn_local(async move { dispatch(future.await) });
+            }
+            Effect::Batch(effects) => {
+                for effect in effects {
+                    self.run_effect_with(state, effect, retry, dispatch.clone());
+                }
+            }
+            Effect::Retry {
+                effect,
+                attempts,
+                backoff_ms,
+            } => self.run_effect_with(
+                state,
+                *effect,
+                Retry {
+                    attempts,
+                    backoff_ms,
+                },
+                dispatch,
+            ),
+            Effect::Then { first, next } => {
+                // The result comes back as a message, possibly much later, so
+                // the next effect is queued like one and run by the loop in
+                // `dispatch`.
+                let next = RefCell::new(next);
+                let then: Dispatch = Rc::new(move |msg| match (next.borrow_mut())(msg) {
+                    Ok(effect) => dispatch(Box::new(Chained(effect, dispatch.clone()))),
+                    Err(msg) => dispatch(msg),
+                });
+                self.run_effect_with(state, *first, retry, then);
+            }
+            Effect::Log(text) => console_log!("{}", text),
+            Effect::Warn(text) => crate::warn(&text),
+            Effect::Error(text) => crate::error(&text),
+        }
+    }
+
+    fn delay(&self, ms: i32, msg: Box<dyn AnyMessage>, dispatch: Dispatch) {
+        let window = match web_sys::window() {
+            Some(window) => window,
+            None => return,
+        };
+
+        let handle = Rc::new(Cell::new(0));
+        let callback = {
+            let delays = self.delays.clone();
+            let handle = handle.clone();
+            let mut msg = Some(msg);
+            Closure::wrap(Box::new(move || {
+                let msg = msg.take();
+                delays.borrow_mut().remove(&handle.get());
+                if let Some(msg) = msg {
+                    dispatch(msg);
+                }
+            }) as Box<dyn FnMut()>)
+        };
+
+        match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            ms,
+        ) {
+            Ok(id) => {
+                handle.set(id);
+                self.delays.borrow_mut().insert(id, callback);
+            }
+            Err(_) => console_log!("failed to schedule a delay of {}ms", ms),
+        }
+    }
+
+    /// Asks the component whether the location the browser went to on its
+    /// own may be shown. If not, the shown path is put back.
+    fn allow_history_change(&self) -> bool {
+        if !router::location_changed() {
+            return true;
+        }
+        let mut state = match self.state.try_borrow_mut() {
+            Ok(state) => state,
+            Err(_) => return true,
+        };
+
+        if state
+            .component
+            .before_navigate(&router::shown_path(), &router::current_path())
+        {
+            return true;
+        }
+        router::revert();
+        self.render_into(&mut state);
+        false
+    }
+
+    fn render(&self) {
+        // While a message is being processed the loop renders on its own.
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            self.render_into(&mut state);
+        }
+    }
+
+    fn render_into(&self, state: &mut State<C>) {
+        let component = &state.component;
+        let view = hooks::render(&mut state.hooks, || render_view(component));
+
+        #[cfg(all(feature = "dev", target_arch = "wasm32"))]
+        if let Some(warning) = impure_view_warning(component, &mut state.hooks, &view) {
+            crate::warn(&warning);
+        }
+
+        // Before mounting, so new elements never show up unstyled.
+        state
+            .styles
+            .add(&state.root, state.component.name(), state.component.css());
+        state.styles.add_embedded(&state.root, &view);
+
+        let active = focus::active_element();
+        let saved_focus = focus::save();
+        state.removed.clear();
+
+        let mut mounted = match state.rendered.take() {
+            Some((old, mut mounted)) => {
+                mounted.apply(diff(&old, &view), &self.document, &mut state.removed);
+                mounted
+            }
+            None => {
+                let mounted = Mounted::create(&view, &self.document);
+                state.root.set_text_content(None);
+                state.root.append_child(&mounted.dom).unwrap();
+                mounted
+            }
+        };
+
+        if let Some(saved_focus) = saved_focus {
+            saved_focus.restore();
+        }
+
+        let mut trap_container = None;
+        mounted.sync(&view, &self.dispatcher(), &mut trap_container);
+        state.rendered = Some((view, mounted));
+
+        state.focus_trap = match (state.focus_trap.take(), trap_container) {
+            (None, Some(container)) => {
+                let trigger = active.and_then(|element| element.dyn_into::<HtmlElement>().ok());
+                focus::focus_first(&container);
+                Some(FocusTrap::new(&container, trigger))
+            }
+            (Some(trap), Some(container)) => Some(trap.retarget(&container)),
+            (Some(trap), None) => {
+                trap.release();
+                None
+            }
+            (None, None) => None,
+        };
+
+        match (state.component.unsaved_changes(), &state.unload_guard) {
+            (true, None) => state.unload_guard = UnloadGuard::new(),
+            (false, Some(_)) => state.unload_guard = None,
+            _ => {}
+        }
+
+        state
+            .subscriptions
+            .sync(state.component.subscriptions(), &self.dispatcher());
+
+        router::scroll_to_anchor();
+    }
+}
+
+/// When a dispatched message is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Schedule {
+    /// Right away, rendering for it alone.
+    Now,
+    /// In the next animation frame, together with whatever else comes in
+    /// until then.
+    NextFrame,
+    /// By the loop that is working off the queue already.
+    Running,
+}
+
+/// Queues `msg` and decides when the queue is worked off, with `flushing`
+/// telling whether that is happening right now.
+fn enqueue(
+    queue: &RefCell<VecDeque<Box<dyn AnyMessage>>>,
+    msg: Box<dyn AnyMessage>,
+    batch_mode: BatchMode,
+    flushing: bool,
+) -> Schedule {
+    queue.borrow_mut().push_back(msg);
+
+    match batch_mode {
+        _ if flushing => Schedule::Running,
+        BatchMode::Immediate => Schedule::Now,
+        BatchMode::AnimationFrame => Schedule::NextFrame,
+    }
+}
+
+/// Applies everything in `queue` to the component in `state` and renders
+/// once, then runs the effects against the freshly rendered DOM. Effects can
+/// queue more messages, which are worked off in another round.
+fn work_off<S, C>(
+    state: &mut S,
+    component: fn(&mut S) -> &mut C,
+    queue: &RefCell<VecDeque<Box<dyn AnyMessage>>>,
+    dispatch: &Dispatch,
+    mut render: impl FnMut(&mut S),
+    mut run_effect: impl FnMut(&mut S, Effect, Dispatch),
+) where
+    C: Component,
+    C::Message: 'static,
+{
+    loop {
+        let (updated, effects) = apply_queued(component(state), queue, dispatch);
+
+        if !updated && effects.is_empty() {
+            break;
+        }
+
+        if updated {
+            render(state);
+        }
+
+        for (effect, dispatch) in effects {
+            run_effect(state, effect, dispatch);
+        }
+    }
+}
+
+/// Applies every message in `queue` to `component`, in the order they were
+/// dispatched, including ones queued meanwhile. Returns whether anything was
+/// updated, so the caller renders once, and the effects to run afterwards,
+/// in the same order, with where their messages go.
+fn apply_queued<C>(
+    component: &mut C,
+    queue: &RefCell<VecDeque<Box<dyn AnyMessage>>>,
+    dispatch: &Dispatch,
+) -> (bool, Vec<(Effect, Dispatch)>)
+where
+    C: Component,
+    C::Message: 'static,
+{
+    let mut updated = false;
+    let mut effects = vec![];
+
+    loop {
+        let msg = queue.borrow_mut().pop_front();
+        let msg: Box<dyn Any> = match msg {
+            Some(msg) => msg,
+            None => break,
+        };
+
+        if msg.is::<LocalUpdate>() {
+            updated = true;
+            continue;
+        }
+        let msg = match msg.downcast::<Chained>() {
+            Ok(chained) => {
+                let Chained(effect, dispatch) = *chained;
+                effects.push((effect, dispatch));
+                continue;
+            }
+            Err(msg) => msg,
+        };
+
+        match msg.downcast::<C::Message>() {
+            Ok(msg) => {
+                updated = true;
+                effects.extend(
+                    component
+                        .update(*msg)
+                        .map(|effect| (effect, dispatch.clone())),
+                );
+            }
+            Err(_) => console_log!("dropping a message of an unexpected type"),
+        }
+    }
+
+    (updated, effects)
+}
+
+/// Components embedded in the view render again, in case their state changed
+/// after their node was created.
+fn render_view<C: Component>(component: &C) -> Node {
+    let mut view = component.view();
+    view.refresh();
+    view
+}
+
+/// Rendering the same state twice has to produce the same tree, or `view`
+/// has side effects. Renders `component` again and returns a warning if the
+/// tree differs from `view`, the one it just rendered.
+#[cfg(any(test, all(feature = "dev", target_arch = "wasm32")))]
+fn impure_view_warning<C: Component>(
+    component: &C,
+    hooks: &mut Hooks,
+    view: &Node,
+) -> Option<String> {
+    let again = hooks::render(hooks, || render_view(component));
+    (!view.same_structure(&again)).then(|| {
+        format!(
+            "`{}::view` returned a different tree when called twice on the same state, \
+             it should not have side effects",
+            component.name()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Counter {
+        count: u32,
+    }
+
+    impl Component for Counter {
+        type Message = u32;
+
+        fn view(&self) -> Node {
+            Node::text_of(self.count)
+        }
+
+        fn update(&mut self, by: u32) -> Option<Effect> {
+            self.count += by;
+            Some(Effect::Warn(format!("now {}", self.count)))
+        }
+    }
+
+    #[test]
+    fn queued_messages_are_applied_before_one_render() {
+        let mut counter = Counter::default();
+        let queue = RefCell::new(VecDeque::new());
+        for by in [1u32, 2, 3] {
+            queue
+                .borrow_mut()
+                .push_back(Box::new(by) as Box<dyn AnyMessage>);
+        }
+        let dispatch: Dispatch = Rc::new(|_| {});
+
+        let (updated, effects) = apply_queued(&mut counter, &queue, &dispatch);
+
+        assert!(updated);
+        assert_eq!(counter.count, 6);
+        assert!(queue.borrow().is_empty());
+        let warnings: Vec<_> = effects
+            .iter()
+            .map(|(effect, _)| match effect {
+                Effect::Warn(text) => text.as_str(),
+                _ => panic!("unexpected effect"),
+            })
+            .collect();
+        assert_eq!(warnings, ["now 1", "now 3", "now 6"]);
+    }
+
+    #[test]
+    fn empty_queue_renders_nothing() {
+        let dispatch: Dispatch = Rc::new(|_| {});
+        let (updated, effects) = apply_queued(
+            &mut Counter::default(),
+            &RefCell::new(VecDeque::new()),
+            &dispatch,
+        );
+
+        assert!(!updated);
+        assert!(effects.is_empty());
+    }
+
+    /// Counts how often it was rendered, and shows it.
+    #[derive(Default)]
+    struct Renders(std::cell::Cell<u32>);
+
+    impl Component for Renders {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            self.0.set(self.0.get() + 1);
+            Node::native("p").with_child(Node::text_of(self.0.get()))
+        }
+
+        fn update(&mut self, _: ()) -> Option<Effect> {
+            None
+        }
+    }
+
+    #[test]
+    fn impure_view_warns() {
+        let component = Renders::default();
+        let mut hooks = Hooks::default();
+        let view = hooks::render(&mut hooks, || render_view(&component));
+
+        let warning = impure_view_warning(&component, &mut hooks, &view).unwrap();
+        assert!(warning.contains("Renders::view` returned a different tree"));
+        assert_eq!(component.0.get(), 2);
+    }
+
+    #[test]
+    fn pure_view_does_not_warn() {
+        let component = Counter { count: 3 };
+        let mut hooks = Hooks::default();
+        let view = hooks::render(&mut hooks, || render_view(&component));
+
+        assert_eq!(impure_view_warning(&component, &mut hooks, &view), None);
+    }
+
+    struct Badge(u32);
+
+    impl Component for Badge {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            Node::native("span").with_child(Node::text_of(self.0))
+        }
+
+        fn update(&mut self, _: ()) -> Option<Effect> {
+            None
+        }
+    }
+
+    #[derive(Default)]
+    struct Dashboard {
+        total: u32,
+        unread: u32,
+        starred: u32,
+    }
+
+    impl Component for Dashboard {
+        type Message = ();
+
+        fn view(&self) -> Node {
+            Node::native("main")
+                .with_child(Node::native("h1").with_child(Node::text_of(self.total)))
+                .with_child(Node::custom(Box::new(Badge(self.unread))))
+                .with_child(Node::custom(Box::new(Badge(self.starred))))
+        }
+
+        fn update(&mut self, _: ()) -> Option<Effect> {
+            self.total += 1;
+            self.unread += 1;
+            self.starred += 1;
+            None
+        }
+    }
+
+    #[test]
+    fn nested_components_are_patched_in_one_pass() {
+        let mut dashboard = Dashboard::default();
+        let old = dashboard.view();
+        let queue = RefCell::new(VecDeque::from([Box::new(()) as Box<dyn AnyMessage>]));
+        let dispatch: Dispatch = Rc::new(|_| {});
+
+        let (updated, _) = apply_queued(&mut dashboard, &queue, &dispatch);
+        assert!(updated);
+
+        // One diff of the whole tree, like `render_into` does, covers the
+        // parent and both components.
+        let new = dashboard.view();
+        let patches: Vec<_> = diff(&old, &new)
+            .into_iter()
+            .map(|patch| match patch {
+                crate::diff::Patch::SetText { path, text } => (path, text),
+                patch => panic!("unexpected patch {:?}", patch),
+            })
+            .collect();
+        assert_eq!(
+            patches,
+            [(vec![0, 0], "1"), (vec![1, 0], "1"), (vec![2, 0], "1")]
+        );
+    }
+
+    /// Dispatches a burst of `messages` to a counter and carries out what
+    /// [`enqueue`] schedules, like the runtime does, returning how often the
+    /// counter rendered. A requested animation frame comes after the burst.
+    fn renders_for_burst(batch_mode: BatchMode, messages: u32) -> usize {
+        let mut counter = Counter::default();
+        let queue = RefCell::new(VecDeque::new());
+        let dispatch: Dispatch = Rc::new(|_| {});
+        let mut renders = 0;
+        let mut frame_requested = false;
+
+        let mut flush = |counter: &mut Counter| {
+            work_off(
+                counter,
+                |counter| counter,
+                &queue,
+                &dispatch,
+                |_| renders += 1,
+                |_, _, _| {},
+            )
+        };
+        for _ in 0..messages {
+            match enqueue(&queue, Box::new(1u32), batch_mode, false) {
+                Schedule::Now => flush(&mut counter),
+                Schedule::NextFrame => frame_requested = true,
+                Schedule::Running => unreachable!("nothing is being flushed"),
+            }
+        }
+        if frame_requested {
+            flush(&mut counter);
+        }
+
+        assert_eq!(counter.count, messages);
+        renders
+    }
+
+    #[test]
+    fn batch_modes_render_a_burst_as_expected() {
+        assert_eq!(renders_for_burst(BatchMode::Immediate, 10), 10);
+        assert_eq!(renders_for_burst(BatchMode::AnimationFrame, 10), 1);
+        assert_eq!(renders_for_burst(BatchMode::AnimationFrame, 0), 0);
+    }
+
+    #[test]
+    fn messages_dispatched_while_flushing_are_left_to_the_loop() {
+        let queue = RefCell::new(VecDeque::new());
+        for batch_mode in [BatchMode::Immediate, BatchMode::AnimationFrame] {
+            assert_eq!(
+                enqueue(&queue, Box::new(1u32), batch_mode, true),
+                Schedule::Running
+            );
+        }
+        assert_eq!(queue.borrow().len(), 2);
+    }
+
+    #[test]
+    fn messages_queued_by_effects_render_again() {
+        let mut counter = Counter::default();
+        let queue = RefCell::new(VecDeque::new());
+        let dispatch: Dispatch = Rc::new(|_| {});
+        queue
+            .borrow_mut()
+            .push_back(Box::new(1u32) as Box<dyn AnyMessage>);
+        let mut renders = 0;
+
+        work_off(
+            &mut counter,
+            |counter| counter,
+            &queue,
+            &dispatch,
+            |_| renders += 1,
+            |counter, _, _| {
+                if counter.count < 3 {
+                    queue.borrow_mut().push_back(Box::new(1u32));
+                }
+            },
+        );
+
+        assert_eq!(counter.count, 3);
+        assert_eq!(renders, 3);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{Node, NodeKind};
+
+/// Tags that are kept. Other tags are dropped, but their content stays.
+#[rustfmt::skip]
+const TAGS: &[&str] = &[
+    "a", "abbr", "b", "blockquote", "br", "caption", "code", "dd", "del", "div", "dl", "dt", "em",
+    "figcaption", "figure", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "i", "img", "ins", "kbd",
+    "li", "mark", "ol", "p", "pre", "q", "s", "small", "span", "strong", "sub", "sup", "table",
+    "tbody", "td", "tfoot", "th", "thead", "tr", "u", "ul",
+];
+
+/// Tags without content or closing tag.
+const VOID_TAGS: &[&str] = &["br", "hr", "img"];
+
+/// Tags that are dropped together with everything up to their closing tag.
+#[rustfmt::skip]
+const DROPPED_TAGS: &[&str] = &[
+    "script", "style", "iframe", "frame", "frameset", "object", "embed", "applet", "template",
+    "noscript", "textarea", "title", "svg", "math",
+];
+
+/// Attributes that are kept on any kept tag. `style` and `id` aren't, the
+/// former can load URLs and the latter can shadow globals.
+#[rustfmt::skip]
+const ATTRIBUTES: &[&str] = &[
+    "alt", "cite", "class", "colspan", "datetime", "dir", "height", "href", "lang", "rowspan",
+    "src", "title", "width",
+];
+
+const URL_ATTRIBUTES: &[&str] = &["cite", "href", "src"];
+
+/// Parses `html` into nodes, keeping only what is in the allowlists above.
+pub(crate) fn sanitize(html: &str) -> Vec<Node> {
+    // The root is a placeholder, only its children are returned.
+    let mut open = vec![Node::native("div")];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        if text_end > 0 {
+            push(&mut open, Node::text(decode(&rest[..text_end])));
+            rest = &rest[text_end..];
+            continue;
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = skip_past(rest, '>');
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let (name, after) = tag_name(closing);
+            rest = skip_past(after, '>');
+            close(&mut open, &name);
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (name, after) = tag_name(&rest[1..]);
+            let (attributes, after) = attributes(after);
+            rest = after;
+
+            if DROPPED_TAGS.contains(&name.as_str()) {
+                rest = skip_closing(rest, &name);
+                continue;
+            }
+            let tag = match TAGS.iter().find(|tag| **tag == name) {
+                Some(tag) => tag,
+                None => continue,
+            };
+
+            let mut node = Node::native(*tag);
+            for (key, value) in attributes {
+                if let Some(key) = ATTRIBUTES.iter().find(|allowed| **allowed == key) {
+                    if !URL_ATTRIBUTES.contains(key) || is_safe_url(&value) {
+                        node = node.with_attribute(key, value);
+                    }
+                }
+            }
+
+            if VOID_TAGS.contains(tag) {
+                push(&mut open, node);
+            } else {
+                open.push(node);
+            }
+        } else {
+            push(&mut open, Node::text("<"));
+            rest = &rest[1..];
+        }
+    }
+
+    while open.len() > 1 {
+        let node = open.pop().unwrap();
+        push(&mut open, node);
+    }
+    std::mem::take(&mut open.pop().unwrap().children)
+}
+
+fn push(open: &mut [Node], node: Node) {
+    open.last_mut().unwrap().children.push(node);
+}
+
+/// Closes the innermost open `name` and everything opened inside of it. A
+/// closing tag without an open one is ignored.
+fn close(open: &mut Vec<Node>, name: &str) {
+    let is_open = |node: &Node| matches!(&node.kind, NodeKind::Native { tag } if tag == name);
+    let index = match open.iter().rposition(is_open) {
+        Some(index) if index > 0 => index,
+        _ => return,
+    };
+    while open.len() > index {
+        let node = open.pop().unwrap();
+        push(open, node);
+    }
+}
+
+/// Reads a tag name, lowercased, and returns it with what follows it.
+fn tag_name(html: &str) -> (String, &str) {
+    let end = html
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(html.len());
+    (html[..end].to_ascii_lowercase(), &html[end..])
+}
+
+/// Reads the attributes of a start tag up to and including its `>`.
+fn attributes(mut html: &str) -> (Vec<(String, String)>, &str) {
+    let mut attributes = vec![];
+
+    loop {
+        html = html.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if html.is_empty() {
+            return (attributes, html);
+        }
+        if let Some(after) = html.strip_prefix('>') {
+            return (attributes, after);
+        }
+
+        let end = html
+            .find(|c: char| c.is_ascii_whitespace() || ['=', '>', '/'].contains(&c))
+            .unwrap_or(html.len())
+            .max(1);
+        let name = html[..end].to_ascii_lowercase();
+        html = html[end..].trim_start();
+
+        let value = match html.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, after) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let after = &after[1..];
+                        let end = after.find(quote).unwrap_or(after.len());
+                        (&after[..end], after.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after
+                            .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                            .unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                html = after;
+                decode(value)
+            }
+            None => String::new(),
+        };
+        attributes.push((name, value));
+    }
+}
+
+fn skip_past(html: &str, c: char) -> &str {
+    html.find(c).map_or("", |end| &html[end + 1..])
+}
+
+/// Skips to after the closing tag of `name`, or to the end if there is none.
+fn skip_closing<'a>(html: &'a str, name: &str) -> &'a str {
+    let closing = format!("</{}", name);
+    match html.to_ascii_lowercase().find(&closing) {
+        Some(start) => skip_past(&html[start..], '>'),
+        None => "",
+    }
+}
+
+/// Whether `url` can't run script when followed or loaded. Browsers ignore
+/// whitespace and control characters in the scheme, so do we.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    !["javascript:", "vbscript:", "data:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+/// Decodes character references, so they are neither escaped twice nor
+/// able to hide a `javascript:` URL. Unknown ones are kept as they are.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        match reference(rest) {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The character a reference at the start of `text` stands for, and how
+/// long the reference is.
+fn reference(text: &str) -> Option<(char, usize)> {
+    if let Some(number) = text.strip_prefix("&#") {
+        let (digits, radix, offset) = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 3),
+            None => (number, 10, 2),
+        };
+        let end = digits
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(digits.len());
+        let c = u32::from_str_radix(&digits[..end], radix)
+            .ok()
+            .and_then(char::from_u32)?;
+        let semicolon = digits[end..].starts_with(';') as usize;
+        return Some((c, offset + end + semicolon));
+    }
+
+    [
+        ("&amp;", '&'),
+        ("&lt;", '<'),
+        ("&gt;", '>'),
+        ("&quot;", '"'),
+        ("&apos;", '\''),
+        ("&nbsp;", '\u{a0}'),
+    ]
+    .iter()
+    .find(|(name, _)| text.starts_with(name))
+    .map(|(name, c)| (*c, name.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The sanitized markup, rendered inside of a `<pre>` so it isn't
+    /// indented.
+    fn clean(html: &str) -> String {
+        let pre = sanitize(html)
+            .into_iter()
+            .fold(Node::native("pre"), Node::with_child)
+            .to_html();
+        pre["<pre>".len()..pre.len() - "</pre>".len()].to_string()
+    }
+
+    #[test]
+    fn scripts_are_dropped_with_their_content() {
+        assert_eq!(clean("a<script>alert(1)</script>b"), "ab");
+        assert_eq!(clean("<SCRIPT src=x.js></SCRIPT>ok"), "ok");
+        assert_eq!(clean("<style>p { }</style><iframe src=x></iframe>"), "");
+    }
+
+    #[test]
+    fn event_handlers_and_styles_are_removed() {
+        assert_eq!(
+            clean(r#"<b onclick="steal()" style="color: red" class=x>hi</b>"#),
+            r#"<b class="x">hi</b>"#
+        );
+    }
+
+    #[test]
+    fn script_urls_are_removed() {
+        assert_eq!(clean(r#"<a href="javascript:alert(1)">x</a>"#), "<a>x</a>");
+        assert_eq!(
+            clean(r#"<a href=" JaVa&#x09;script:alert(1)">x</a>"#),
+            "<a>x</a>"
+        );
+        assert_eq!(clean(r#"<img src="data:text/html,x">"#), "<img></img>");
+        assert_eq!(
+            clean(r#"<a href="https://example.com">x</a>"#),
+            r#"<a href="https://example.com">x</a>"#
+        );
+    }
+
+    #[test]
+    fn safe_tags_survive() {
+        assert_eq!(
+            clean("<p>Some <em>very</em> <strong>safe</strong><br>text &amp; more</p>"),
+            "<p>Some <em>very</em> <strong>safe</strong><br></br>text &amp; more</p>"
+        );
+        assert_eq!(clean("<blink>unwrapped</blink>"), "unwrapped");
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{AnyMessage, Node};
+
+/// Renders a `<select>` with an `<option>` per `(value, label)` pair, the
+/// one whose value is `selected` being marked as such. `on_select` receives
+/// the value of every option the user picks.
+pub fn select<M: AnyMessage>(
+    options: &[(&str, &str)],
+    selected: &str,
+    on_select: impl Fn(String) -> M + 'static,
+) -> Node {
+    options
+        .iter()
+        .fold(Node::native("select"), |select, &(value, label)| {
+            let option = Node::native("option")
+                .with_attribute("value", value)
+                .with_child(Node::text(label));
+
+            select.with_child(if value == selected {
+                option.with_attribute("selected", true)
+            } else {
+                option
+            })
+        })
+        // The attribute only picks the initial option, this keeps the
+        // element in sync when the model changes.
+        .with_value(selected)
+        .on_input(on_select)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use super::*;
+    use crate::testing::find;
+
+    const SIZES: &[(&str, &str)] = &[("s", "Small"), ("m", "Medium"), ("l", "Large")];
+
+    #[test]
+    fn selected_option_carries_attribute() {
+        let select = select(SIZES, "m", |size| size);
+
+        let selected = find(&select, "option[selected]").unwrap();
+        assert_eq!(selected.attributes["value"].as_text(), "m");
+        assert!(find(&select, "option[value=s]")
+            .unwrap()
+            .attributes
+            .get("selected")
+            .is_none());
+        assert_eq!(select.value.as_deref(), Some("m"));
+    }
+
+    #[test]
+    fn picking_an_option_reports_its_value() {
+        let select = select(SIZES, "m", |size| size);
+
+        let msg: Box<dyn Any> = (select.on_input.as_ref().unwrap())("l".to_string());
+        assert_eq!(*msg.downcast::<String>().unwrap(), "l");
+    }
+}
@@ -0,0 +1,205 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{Document, Event, EventTarget, Window};
+
+use crate::{dom::Dispatch, AnyMessage};
+
+type Handler = Rc<dyn Fn(&Event) -> Option<Box<dyn AnyMessage>>>;
+
+enum Source {
+    Every(i32),
+    /// Events on the window, any of which calls the handler.
+    Window(Vec<String>),
+    Document(Vec<String>),
+}
+
+/// Something outside of the view the component listens to, like a timer or
+/// an event on the window, see [`Component::subscriptions`].
+///
+/// Subscriptions are told apart by their key. One that is returned again
+/// keeps running and only gets the new callback, one that is missing after
+/// a render is stopped.
+///
+/// [`Component::subscriptions`]: crate::Component::subscriptions
+pub struct Subscription {
+    key: String,
+    source: Source,
+    handler: Handler,
+}
+
+impl Subscription {
+    /// Dispatches the message returned by `f` every `ms` milliseconds.
+    pub fn every<M: AnyMessage>(ms: i32, f: impl Fn() -> M + 'static) -> Self {
+        Self {
+            key: format!("every {}", ms),
+            source: Source::Every(ms),
+            handler: Rc::new(move |_| Some(Box::new(f()))),
+        }
+    }
+
+    /// Calls `f` with every `event` fired on the window, like `"resize"` or
+    /// `"keydown"`, and dispatches the message it returns, if any.
+    pub fn on_window<M: AnyMessage>(
+        event: &str,
+        f: impl Fn(&Event) -> Option<M> + 'static,
+    ) -> Self {
+        Self {
+            key: format!("window {}", event),
+            source: Source::Window(vec![event.to_string()]),
+            handler: Rc::new(move |event| f(event).map(|msg| Box::new(msg) as Box<dyn AnyMessage>)),
+        }
+    }
+
+    /// Dispatches the message `f` returns for whether the page is visible
+    /// whenever that changes, like when the user switches to another tab or
+    /// minimizes the window and comes back, to pause polling or animations
+    /// in the meantime.
+    pub fn on_visibility_change<M: AnyMessage>(f: impl Fn(bool) -> M + 'static) -> Self {
+        Self {
+            key: "document visibilitychange".to_string(),
+            source: Source::Document(vec!["visibilitychange".to_string()]),
+            handler: Rc::new(move |event| {
+                let document = event.target().and_then(|target| target.dyn_into().ok());
+                let visible = document.is_none_or(|document: Document| !document.hidden());
+                Some(Box::new(f(visible)))
+            }),
+        }
+    }
+
+    /// Dispatches the message `f` returns for whether the browser is online
+    /// whenever that changes. Read the state it starts out in with
+    /// [`is_online`](crate::is_online).
+    pub fn on_connectivity_change<M: AnyMessage>(f: impl Fn(bool) -> M + 'static) -> Self {
+        Self {
+            key: "window online offline".to_string(),
+            source: Source::Window(vec!["online".to_string(), "offline".to_string()]),
+            handler: Rc::new(move |event| Some(Box::new(f(event.type_() == "online")))),
+        }
+    }
+
+    /// Replaces the key, which is made of the kind and the interval or event
+    /// name otherwise, to subscribe to the same thing twice.
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+}
+
+/// A started subscription, stopped when dropped.
+struct Running {
+    window: Window,
+    source: Source,
+    handler: Rc<RefCell<Handler>>,
+    listener: Closure<dyn FnMut(Event)>,
+    interval: Option<i32>,
+}
+
+impl Running {
+    fn start(subscription: Subscription, dispatch: &Dispatch) -> Option<Self> {
+        let window = web_sys::window()?;
+        let handler = Rc::new(RefCell::new(subscription.handler));
+
+        let listener = {
+            let handler = handler.clone();
+            let dispatch = dispatch.clone();
+            Closure::wrap(Box::new(move |event: Event| {
+                let handler = handler.borrow().clone();
+                if let Some(msg) = handler(&event) {
+                    dispatch(msg);
+                }
+            }) as Box<dyn FnMut(Event)>)
+        };
+
+        let interval = match &subscription.source {
+            Source::Every(ms) => Some(
+                window
+                    .set_interval_with_callback_and_timeout_and_arguments_0(
+                        listener.as_ref().unchecked_ref(),
+                        *ms,
+                    )
+                    .ok()?,
+            ),
+            Source::Window(events) | Source::Document(events) => {
+                let target = target(&window, &subscription.source)?;
+                for event in events {
+                    target
+                        .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                        .ok()?;
+                }
+                None
+            }
+        };
+
+        Some(Self {
+            window,
+            source: subscription.source,
+            handler,
+            listener,
+            interval,
+        })
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        match &self.source {
+            Source::Every(_) => {
+                if let Some(interval) = self.interval {
+                    self.window.clear_interval_with_handle(interval);
+                }
+            }
+            Source::Window(events) | Source::Document(events) => {
+                if let Some(target) = target(&self.window, &self.source) {
+                    for event in events {
+                        let _ = target.remove_event_listener_with_callback(
+                            event,
+                            self.listener.as_ref().unchecked_ref(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What the listener of an event subscription is added to.
+fn target(window: &Window, source: &Source) -> Option<EventTarget> {
+    match source {
+        Source::Document(_) => window.document().map(Into::into),
+        _ => Some(window.clone().into()),
+    }
+}
+
+/// The running subscriptions of a runtime by key.
+#[derive(Default)]
+pub(crate) struct Subscriptions {
+    running: HashMap<String, Running>,
+}
+
+impl Subscriptions {
+    /// Starts the subscriptions that are new, stops the ones that are gone
+    /// and hands the new callbacks to the ones that keep running.
+    pub(crate) fn sync(&mut self, subscriptions: Vec<Subscription>, dispatch: &Dispatch) {
+        let mut running = HashMap::with_capacity(subscriptions.len());
+
+        for subscription in subscriptions {
+            let key = subscription.key.clone();
+
+            match self.running.remove(&key) {
+                Some(current) => {
+                    *current.handler.borrow_mut() = subscription.handler;
+                    running.insert(key, current);
+                }
+                None => {
+                    if let Some(started) = Running::start(subscription, dispatch) {
+                        running.insert(key, started);
+                    }
+                }
+            }
+        }
+
+        // Whatever is left over was not returned this time and stops here.
+        self.running = running;
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{Node, NodeKind};
+
+/// Renders `fallback` in place of a subtree while any custom component in it
+/// is not [`ready`](crate::Component::ready).
+///
+/// Readiness is up to the component, usually a flag in its model that flips
+/// once its data arrived. Whatever changes that flag has to cause a render,
+/// so suspended components re-check on every render of their parent.
+pub struct Suspense {
+    pub fallback: Node,
+}
+
+impl Suspense {
+    pub fn new(fallback: Node) -> Self {
+        Self { fallback }
+    }
+
+    pub fn view(self, child: Node) -> Node {
+        if is_ready(&child) {
+            child
+        } else {
+            self.fallback
+        }
+    }
+}
+
+fn is_ready(node: &Node) -> bool {
+    let ready = match &node.kind {
+        NodeKind::Custom {
+            component,
+            rendered,
+            ..
+        } => component.ready() && is_ready(rendered),
+        _ => true,
+    };
+
+    ready && node.children.iter().all(is_ready)
+}
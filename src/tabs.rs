@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use crate::{roving::roving_along, AnyMessage, Node};
+
+/// A tab list with a panel per tab, following the ARIA tabs pattern. `tabs`
+/// pairs the label of every tab with its panel, `selected` is the index of
+/// the one that is shown and `on_select` receives the index to show next.
+///
+/// Left and Right go to the previous and next tab, wrapping around, Home
+/// and End to the first and last, and select it right away. Only the
+/// selected tab can be reached with Tab, the next Tab moves on into its
+/// panel. Clicks, Enter and Space select a tab too.
+///
+/// Tabs and panels refer to each other through ids starting with `id`, which
+/// has to be unique on the page. Panels that aren't selected stay rendered
+/// but `hidden`, so what is typed into them survives switching tabs.
+pub fn tabs<M: AnyMessage>(
+    id: &str,
+    tabs: Vec<(String, Node)>,
+    selected: usize,
+    on_select: impl Fn(usize) -> M + 'static,
+) -> Node {
+    let selected = selected.min(tabs.len().saturating_sub(1));
+    let on_select = Rc::new(on_select);
+    let tab_id = |i: usize| format!("{}-tab-{}", id, i);
+    let panel_id = |i: usize| format!("{}-panel-{}", id, i);
+
+    let (labels, panels): (Vec<_>, Vec<_>) = tabs.into_iter().unzip();
+
+    let labels = labels
+        .into_iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let on_select = on_select.clone();
+            Node::native("button")
+                .type_("button")
+                .id(tab_id(i))
+                .with_attribute("aria-controls", panel_id(i))
+                .with_child(Node::text(label))
+                .on_click_with(move || on_select(i))
+                .tab_role(i == selected)
+        })
+        .collect();
+
+    let list = roving_along(
+        Node::native("div").with_attribute("role", "tablist"),
+        labels,
+        selected,
+        move |i| on_select(i),
+        ("ArrowRight", "ArrowLeft"),
+    );
+
+    panels
+        .into_iter()
+        .enumerate()
+        .fold(Node::native("div").with_child(list), |tabs, (i, panel)| {
+            tabs.with_child(
+                Node::native("div")
+                    .with_attribute("role", "tabpanel")
+                    .id(panel_id(i))
+                    .with_attribute("aria-labelledby", tab_id(i))
+                    .with_attribute("tabindex", 0)
+                    .hidden(i != selected)
+                    .with_child(panel),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mount, MockElement};
+    use crate::{Modifiers, NodeAttributeValue};
+
+    fn settings(selected: usize) -> Node {
+        let tab = |label: &str| {
+            (
+                label.to_string(),
+                Node::native("p").with_child(Node::text(format!("{} settings", label))),
+            )
+        };
+        tabs(
+            "settings",
+            vec![tab("Profile"), tab("Account"), tab("Privacy")],
+            selected,
+            |i| i,
+        )
+    }
+
+    fn is_hidden(panel: &MockElement) -> bool {
+        panel
+            .attributes
+            .get("hidden")
+            .is_some_and(NodeAttributeValue::is_present)
+    }
+
+    fn selected(msg: Option<Box<dyn AnyMessage>>) -> usize {
+        let msg: Box<dyn std::any::Any> = msg.unwrap();
+        *msg.downcast::<usize>().unwrap()
+    }
+
+    #[test]
+    fn only_the_selected_panel_is_shown() {
+        let root = mount(&settings(1));
+        let root = root.as_element().unwrap();
+        let list = root.child_element(0).unwrap();
+        let panels: Vec<_> = (1..=3).map(|i| root.child_element(i).unwrap()).collect();
+
+        let shown: Vec<_> = panels.iter().filter(|panel| !is_hidden(panel)).collect();
+        assert_eq!(shown.len(), 1);
+        assert_eq!(shown[0].text(), "Account settings");
+        assert_eq!(
+            shown[0].attributes["id"],
+            NodeAttributeValue::from("settings-panel-1")
+        );
+        assert_eq!(
+            shown[0].attributes["aria-labelledby"],
+            NodeAttributeValue::from("settings-tab-1")
+        );
+
+        let tab = list.child_element(1).unwrap();
+        assert_eq!(
+            tab.attributes["aria-selected"],
+            NodeAttributeValue::from("true")
+        );
+        assert_eq!(
+            tab.attributes["aria-controls"],
+            NodeAttributeValue::from("settings-panel-1")
+        );
+        assert_eq!(
+            list.child_element(0).unwrap().attributes["aria-selected"],
+            NodeAttributeValue::from("false")
+        );
+    }
+
+    #[test]
+    fn selecting_another_tab_shows_its_panel() {
+        let root = mount(&settings(0));
+        let list = root.as_element().unwrap().child_element(0).unwrap();
+
+        // Key presses on a tab bubble up to the tab list, which handles them.
+        assert_eq!(selected(list.child_element(2).unwrap().click()), 2);
+        assert_eq!(selected(list.press("ArrowRight", Modifiers::NONE)), 1);
+        assert_eq!(selected(list.press("ArrowLeft", Modifiers::NONE)), 2);
+        assert_eq!(selected(list.press("End", Modifiers::NONE)), 2);
+
+        let root = mount(&settings(2));
+        let root = root.as_element().unwrap();
+        assert!(is_hidden(root.child_element(1).unwrap()));
+        assert!(!is_hidden(root.child_element(3).unwrap()));
+        assert_eq!(root.child_element(3).unwrap().text(), "Privacy settings");
+    }
+}
@@ -0,0 +1,592 @@
+//! Helpers for exercising views without a browser.
+
+use crate::{
+    diff::resolve, AnyMessage, Attributes, ClickHandler, InputHandler, KeyBinding, Modifiers, Node,
+    NodeAttributeValue, NodeKind,
+};
+
+/// Runs the click handler of `node` the way the runtime would and returns
+/// the message it produced, or `None` if the node has no click handler.
+///
+/// Downcast the result to the component's message type to inspect it.
+pub fn simulate_click(node: &Node) -> Option<Box<dyn AnyMessage>> {
+    node.on_click.as_ref().map(|on_click| on_click())
+}
+
+/// Runs the input handler of `node` as if the user had changed its value to
+/// `value` and returns the message it produced, or `None` if the node has no
+/// input handler.
+pub fn simulate_input(node: &Node, value: &str) -> Option<Box<dyn AnyMessage>> {
+    node.on_input
+        .as_ref()
+        .map(|on_input| on_input(value.to_string()))
+}
+
+/// The first element in `node` or below it, in document order, that matches
+/// `selector`. Only single compound selectors are supported, made of an
+/// optional tag followed by any of `#id`, `.class`, `[name]` and
+/// `[name=value]`, like `button.primary[type=submit]`.
+///
+/// # Panics
+///
+/// If `selector` can't be parsed.
+pub fn find<'a>(node: &'a Node, selector: &str) -> Option<&'a Node> {
+    let parts = parse_selector(selector)
+        .unwrap_or_else(|| panic!("`{}` is not a supported selector", selector));
+    find_parsed(node, &parts)
+}
+
+/// Finds the element matching `selector` like [`find`] and panics, showing
+/// the actual and the expected value, unless its attribute `key` equals
+/// `expected`. Values are compared as the text the DOM gets, so `3` matches
+/// `"3"`.
+///
+/// ```ignore
+/// #[test]
+/// fn submit_button() {
+///     assert_attribute(&Form::default().view(), "button", "type", "submit");
+/// }
+/// ```
+pub fn assert_attribute(
+    node: &Node,
+    selector: &str,
+    key: &str,
+    expected: impl Into<NodeAttributeValue>,
+) {
+    let expected = expected.into();
+    let element =
+        find(node, selector).unwrap_or_else(|| panic!("no element matches `{}`", selector));
+
+    match element.attributes.get(key) {
+        Some(actual) => assert!(
+            actual.as_text() == expected.as_text(),
+            "attribute `{}` of `{}` does not match\n\nexpected: {}\nactual:   {}",
+            key,
+            selector,
+            expected,
+            actual
+        ),
+        None => panic!(
+            "`{}` has no attribute `{}`\n\nexpected: {}",
+            selector, key, expected
+        ),
+    }
+}
+
+/// The innermost element in `node` or below it whose text content, ignoring
+/// surrounding whitespace, is `text`, like the button labeled `Save`. If
+/// the element has no click handler but one around it with the same text
+/// has, that one is returned, the way a click on the label of
+/// `<button><span>Save</span></button>` reaches the button.
+///
+/// ```ignore
+/// #[test]
+/// fn save() {
+///     let view = Editor::default().view();
+///     let button = find_by_text(&view, "Save").unwrap();
+///     assert!(simulate_click(button).is_some());
+/// }
+/// ```
+pub fn find_by_text<'a>(node: &'a Node, text: &str) -> Option<&'a Node> {
+    find_text(node, &|content| content.trim() == text)
+}
+
+/// Like [`find_by_text`], but finds the innermost element whose text content
+/// contains `text`, or the one with a click handler around it.
+pub fn find_by_text_containing<'a>(node: &'a Node, text: &str) -> Option<&'a Node> {
+    find_text(node, &|content| content.contains(text))
+}
+
+fn find_text<'a>(node: &'a Node, matches: &dyn Fn(&str) -> bool) -> Option<&'a Node> {
+    let node = resolve(node);
+
+    if let NodeKind::Native { .. } = node.kind {
+        let inner = node
+            .children
+            .iter()
+            .find_map(|child| find_text(child, matches));
+        match inner {
+            Some(inner) if inner.on_click.is_none() && node.on_click.is_some() => {
+                Some(if matches(&text_content(node)) {
+                    node
+                } else {
+                    inner
+                })
+            }
+            Some(inner) => Some(inner),
+            None => Some(node).filter(|node| matches(&text_content(node))),
+        }
+    } else {
+        None
+    }
+}
+
+/// The concatenated text below `node`, like `textContent`.
+fn text_content(node: &Node) -> String {
+    match &resolve(node).kind {
+        NodeKind::Text(text) => text.clone(),
+        _ => resolve(node).children.iter().map(text_content).collect(),
+    }
+}
+
+enum SelectorPart {
+    Tag(String),
+    Id(String),
+    Class(String),
+    Attribute(String, Option<String>),
+}
+
+fn parse_selector(selector: &str) -> Option<Vec<SelectorPart>> {
+    fn is_name(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '-' || c == '_'
+    }
+
+    let mut parts = vec![];
+    let mut rest = selector.trim();
+
+    let tag_len = rest.find(|c| !is_name(c)).unwrap_or(rest.len());
+    if tag_len > 0 {
+        parts.push(SelectorPart::Tag(rest[..tag_len].to_string()));
+        rest = &rest[tag_len..];
+    }
+
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[1..];
+        match c {
+            '#' | '.' => {
+                let len = rest.find(|c| !is_name(c)).unwrap_or(rest.len());
+                if len == 0 {
+                    return None;
+                }
+                let name = rest[..len].to_string();
+                parts.push(if c == '#' {
+                    SelectorPart::Id(name)
+                } else {
+                    SelectorPart::Class(name)
+                });
+                rest = &rest[len..];
+            }
+            '[' => {
+                let end = rest.find(']')?;
+                let (name, value) = match rest[..end].split_once('=') {
+                    Some((name, value)) => {
+                        let value = value.trim();
+                        let value = value
+                            .strip_prefix('"')
+                            .and_then(|value| value.strip_suffix('"'))
+                            .unwrap_or(value);
+                        (name, Some(value.to_string()))
+                    }
+                    None => (&rest[..end], None),
+                };
+                parts.push(SelectorPart::Attribute(name.trim().to_string(), value));
+                rest = &rest[end + 1..];
+            }
+            _ => return None,
+        }
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts)
+    }
+}
+
+fn find_parsed<'a>(node: &'a Node, parts: &[SelectorPart]) -> Option<&'a Node> {
+    let node = resolve(node);
+
+    if matches(node, parts) {
+        return Some(node);
+    }
+
+    node.children
+        .iter()
+        .find_map(|child| find_parsed(child, parts))
+}
+
+fn matches(node: &Node, parts: &[SelectorPart]) -> bool {
+    let tag = match &node.kind {
+        NodeKind::Native { tag } => tag,
+        _ => return false,
+    };
+    let attribute = |name: &str| {
+        let value = node.attributes.get(name).filter(|value| value.is_present());
+        value.map(NodeAttributeValue::as_text)
+    };
+
+    parts.iter().all(|part| match part {
+        SelectorPart::Tag(name) => tag.eq_ignore_ascii_case(name),
+        SelectorPart::Id(id) => attribute("id").as_deref() == Some(id),
+        SelectorPart::Class(class) => {
+            attribute("class").is_some_and(|classes| classes.split_whitespace().any(|x| x == class))
+        }
+        SelectorPart::Attribute(name, None) => attribute(name).is_some(),
+        SelectorPart::Attribute(name, Some(value)) => attribute(name).as_deref() == Some(value),
+    })
+}
+
+/// A node of the tree [`mount`] builds, standing in for the DOM node the
+/// runtime would create.
+#[derive(Debug)]
+pub enum MockNode {
+    Element(MockElement),
+    Text(String),
+}
+
+/// An element of the tree [`mount`] builds, with what the runtime would set
+/// on the DOM element and the handlers it would listen with.
+pub struct MockElement {
+    pub tag: String,
+    pub attributes: Attributes,
+    /// The `value` property, which isn't an attribute.
+    pub value: Option<String>,
+    pub children: Vec<MockNode>,
+    on_click: Option<ClickHandler>,
+    on_input: Option<InputHandler>,
+    on_blur: Option<ClickHandler>,
+    on_key: Vec<KeyBinding>,
+}
+
+impl std::fmt::Debug for MockElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockElement")
+            .field("tag", &self.tag)
+            .field("attributes", &self.attributes)
+            .field("value", &self.value)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+/// Builds the element tree the runtime would mount for `node`, in memory, so
+/// its structure can be checked without a browser. Components are replaced
+/// with what they rendered, like in the DOM.
+///
+/// ```ignore
+/// #[test]
+/// fn app_view() {
+///     let root = mount(&App::default().view());
+///     let button = root.as_element().unwrap().child_element(0).unwrap();
+///     assert_eq!(button.tag, "button");
+///     assert_eq!(button.text(), "Increment");
+/// }
+/// ```
+pub fn mount(node: &Node) -> MockNode {
+    let node = resolve(node);
+
+    match &node.kind {
+        NodeKind::Native { tag } => MockNode::Element(MockElement {
+            tag: tag.clone(),
+            attributes: node.attributes.clone(),
+            value: node.value.clone(),
+            children: node.children.iter().map(mount).collect(),
+            on_click: node.on_click.clone(),
+            on_input: node.on_input.clone(),
+            on_blur: node.on_blur.clone(),
+            on_key: node.on_key.clone(),
+        }),
+        NodeKind::Text(text) => MockNode::Text(text.clone()),
+        NodeKind::Custom { .. } => unreachable!("custom nodes are resolved"),
+    }
+}
+
+impl MockNode {
+    pub fn as_element(&self) -> Option<&MockElement> {
+        match self {
+            MockNode::Element(element) => Some(element),
+            MockNode::Text(_) => None,
+        }
+    }
+
+    /// The concatenated text of this node and everything below it, like
+    /// `textContent`.
+    pub fn text(&self) -> String {
+        match self {
+            MockNode::Element(element) => element.text(),
+            MockNode::Text(text) => text.clone(),
+        }
+    }
+}
+
+impl MockElement {
+    /// The `index`th child that is an element, skipping text.
+    pub fn child_element(&self, index: usize) -> Option<&MockElement> {
+        self.children
+            .iter()
+            .filter_map(MockNode::as_element)
+            .nth(index)
+    }
+
+    pub fn text(&self) -> String {
+        self.children.iter().map(MockNode::text).collect()
+    }
+
+    /// Clicks the element and returns the message its click handler
+    /// produced, like [`simulate_click`].
+    pub fn click(&self) -> Option<Box<dyn AnyMessage>> {
+        self.on_click.as_ref().map(|on_click| on_click())
+    }
+
+    /// Changes the value of the element and returns the message its input
+    /// handler produced, like [`simulate_input`].
+    pub fn input(&self, value: &str) -> Option<Box<dyn AnyMessage>> {
+        self.on_input
+            .as_ref()
+            .map(|on_input| on_input(value.to_string()))
+    }
+
+    /// Moves focus away from the element and returns the message its blur
+    /// handler produced.
+    pub fn blur(&self) -> Option<Box<dyn AnyMessage>> {
+        self.on_blur.as_ref().map(|on_blur| on_blur())
+    }
+
+    /// Presses `key` with exactly the `modifiers` held while the element has
+    /// focus and returns the message its binding for that key produced, see
+    /// [`Node::on_key`].
+    pub fn press(&self, key: &str, modifiers: Modifiers) -> Option<Box<dyn AnyMessage>> {
+        self.on_key
+            .iter()
+            .find(|(bound, bound_modifiers, _)| {
+                *bound_modifiers == modifiers && bound.eq_ignore_ascii_case(key)
+            })
+            .map(|(_, _, f)| f())
+    }
+}
+
+/// Compares `node.to_html()` against `snapshots/<name>.html` in the crate
+/// root and panics if they differ. A missing snapshot is written instead, so
+/// the first run records it; delete the file to re-record. Panics too if the
+/// snapshot exists but can't be read.
+///
+/// ```ignore
+/// #[test]
+/// fn app_view() {
+///     assert_html_snapshot(&App.view(), "app");
+/// }
+/// ```
+#[cfg(feature = "snapshot")]
+pub fn assert_html_snapshot(node: &Node, name: &str) {
+    let dir = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+        .join("snapshots");
+    let path = dir.join(format!("{}.html", name));
+    let html = node.to_html();
+
+    match std::fs::read_to_string(&path) {
+        Ok(snapshot) => assert!(
+            snapshot == html,
+            "snapshot `{}` does not match\n\nexpected:\n{}\n\nactual:\n{}",
+            name,
+            snapshot,
+            html
+        ),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(&path, html).unwrap();
+        }
+        Err(error) => panic!("snapshot `{}` can't be read: {}", name, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Msg {
+        Save,
+        Rename(String),
+    }
+
+    fn message(msg: Option<Box<dyn AnyMessage>>) -> Msg {
+        let msg: Box<dyn Any> = msg.expect("no message was produced");
+        *msg.downcast::<Msg>().expect("not a `Msg`")
+    }
+
+    #[test]
+    fn click_produces_message() {
+        let button = Node::native("button").on_click(Msg::Save);
+
+        assert_eq!(message(simulate_click(&button)), Msg::Save);
+    }
+
+    #[test]
+    fn click_without_handler_produces_nothing() {
+        assert!(simulate_click(&Node::native("button")).is_none());
+    }
+
+    #[test]
+    fn input_produces_message_with_value() {
+        let input = Node::native("input").on_input(Msg::Rename);
+
+        assert_eq!(
+            message(simulate_input(&input, "draft")),
+            Msg::Rename("draft".to_string())
+        );
+    }
+
+    #[test]
+    fn input_without_handler_produces_nothing() {
+        assert!(simulate_input(&Node::native("input"), "draft").is_none());
+    }
+
+    #[test]
+    fn mount_builds_element_tree() {
+        let view = Node::native("div")
+            .with_child(
+                Node::native("button")
+                    .with_attribute("class", "primary")
+                    .on_click(Msg::Save)
+                    .with_child(Node::text("Save")),
+            )
+            .with_child(Node::text(" or "))
+            .with_child(
+                Node::native("input")
+                    .with_value("draft")
+                    .on_input(Msg::Rename),
+            );
+
+        let root = mount(&view);
+        let root = root.as_element().unwrap();
+        assert_eq!(root.tag, "div");
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(root.text(), "Save or ");
+
+        let button = root.child_element(0).unwrap();
+        assert_eq!(button.attributes["class"].as_text(), "primary");
+        assert_eq!(message(button.click()), Msg::Save);
+
+        let input = root.child_element(1).unwrap();
+        assert_eq!(input.value.as_deref(), Some("draft"));
+        assert_eq!(
+            message(input.input("sent")),
+            Msg::Rename("sent".to_string())
+        );
+        assert!(input.click().is_none());
+    }
+
+    fn form() -> Node {
+        Node::native("form").with_child(
+            Node::native("button")
+                .type_("submit")
+                .with_attribute("tabindex", 0),
+        )
+    }
+
+    #[test]
+    fn assert_attribute_passes_for_matching_value() {
+        assert_attribute(&form(), "button", "type", "submit");
+        assert_attribute(&form(), "button", "tabindex", "0");
+    }
+
+    #[test]
+    #[should_panic(expected = "attribute `type` of `button` does not match")]
+    fn assert_attribute_fails_for_other_value() {
+        assert_attribute(&form(), "button", "type", "reset");
+    }
+
+    #[test]
+    #[should_panic(expected = "`button` has no attribute `disabled`")]
+    fn assert_attribute_fails_for_missing_attribute() {
+        assert_attribute(&form(), "button", "disabled", true);
+    }
+
+    fn toolbar() -> Node {
+        Node::native("div")
+            .with_child(
+                Node::native("button")
+                    .on_click(Msg::Save)
+                    .with_child(Node::native("span").with_child(Node::text("  Save "))),
+            )
+            .with_child(Node::native("button").with_child(Node::text("Save as")))
+    }
+
+    #[test]
+    fn find_by_text_finds_innermost_element() {
+        let view = Node::native("p").with_child(Node::native("b").with_child(Node::text("Note")));
+        let note = find_by_text(&view, "Note").unwrap();
+
+        assert!(matches!(&note.kind, NodeKind::Native { tag } if tag == "b"));
+        assert!(find_by_text(&toolbar(), "Save as")
+            .unwrap()
+            .on_click
+            .is_none());
+        assert!(find_by_text(&toolbar(), "Delete").is_none());
+    }
+
+    #[test]
+    fn find_by_text_finds_the_button_around_a_label() {
+        let view = toolbar();
+        let save = find_by_text(&view, "Save").unwrap();
+
+        assert!(matches!(&save.kind, NodeKind::Native { tag } if tag == "button"));
+        assert_eq!(message(simulate_click(save)), Msg::Save);
+    }
+
+    #[test]
+    fn find_by_text_keeps_the_clickable_element_closest_to_the_text() {
+        let view = Node::native("li")
+            .on_click(Msg::Rename("row".to_string()))
+            .with_child(
+                Node::native("button")
+                    .on_click(Msg::Save)
+                    .with_child(Node::text("Save")),
+            );
+
+        assert_eq!(
+            message(simulate_click(find_by_text(&view, "Save").unwrap())),
+            Msg::Save
+        );
+    }
+
+    #[test]
+    fn find_by_text_containing_matches_part() {
+        let view = toolbar();
+
+        let found = find_by_text_containing(&view, "as").unwrap();
+        assert_eq!(text_content(found), "Save as");
+    }
+
+    fn badge(count: u32) -> Node {
+        Node::native("span").with_child(Node::text_of(count))
+    }
+
+    #[test]
+    fn mount_replaces_components_with_what_they_rendered() {
+        let root = mount(&Node::native("p").with_child(Node::pure(badge, 3)));
+        let badge = root.as_element().unwrap().child_element(0).unwrap();
+
+        assert_eq!(badge.tag, "span");
+        assert_eq!(badge.text(), "3");
+    }
+
+    #[cfg(feature = "snapshot")]
+    fn app_view() -> Node {
+        Node::native("div")
+            .with_attribute("class", "app")
+            .with_child(Node::native("h1").with_child(Node::text("Hello world")))
+            .with_child(
+                Node::native("button")
+                    .type_("button")
+                    .on_click(Msg::Save)
+                    .with_child(Node::text("Save")),
+            )
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn app_view_matches_snapshot() {
+        assert_html_snapshot(&app_view(), "app");
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    #[should_panic(expected = "snapshot `app` does not match")]
+    fn changed_app_view_fails_snapshot() {
+        let view = app_view().with_child(Node::native("p"));
+        assert_html_snapshot(&view, "app");
+    }
+}
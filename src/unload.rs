@@ -0,0 +1,35 @@
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{BeforeUnloadEvent, Window};
+
+/// Asks for confirmation before the page is closed or reloaded, for as long
+/// as it is alive.
+pub(crate) struct UnloadGuard {
+    window: Window,
+    listener: Closure<dyn FnMut(BeforeUnloadEvent)>,
+}
+
+impl UnloadGuard {
+    pub(crate) fn new() -> Option<Self> {
+        let window = web_sys::window()?;
+        let listener = Closure::wrap(Box::new(|event: BeforeUnloadEvent| {
+            event.prevent_default();
+            // Older browsers only ask when a return value is set.
+            event.set_return_value("");
+        }) as Box<dyn FnMut(BeforeUnloadEvent)>);
+
+        window
+            .add_event_listener_with_callback("beforeunload", listener.as_ref().unchecked_ref())
+            .ok()?;
+
+        Some(Self { window, listener })
+    }
+}
+
+impl Drop for UnloadGuard {
+    fn drop(&mut self) {
+        let _ = self.window.remove_event_listener_with_callback(
+            "beforeunload",
+            self.listener.as_ref().unchecked_ref(),
+        );
+    }
+}
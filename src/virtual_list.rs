@@ -0,0 +1,56 @@
+use crate::{AnyMessage, Node};
+
+/// Rows rendered above and below the visible ones, so fast scrolling doesn't
+/// show empty space before the next render.
+const BUFFER: usize = 5;
+
+/// Renders a scrollable list of `count` rows that are `item_height` pixels
+/// tall, creating nodes only for the rows visible in a viewport of `height`
+/// pixels plus a small buffer.
+///
+/// The caller keeps track of the scroll position: `on_scroll` maps the new
+/// `scrollTop` to a message, and the position from the model is passed back
+/// in as `scroll_top`. A spacer as tall as the whole list keeps the
+/// scrollbar accurate.
+pub fn virtual_list<M: AnyMessage>(
+    count: usize,
+    item_height: u32,
+    height: u32,
+    scroll_top: i32,
+    render: impl Fn(usize) -> Node,
+    on_scroll: impl Fn(i32) -> M + 'static,
+) -> Node {
+    let item_height = item_height.max(1) as usize;
+    let first = (scroll_top.max(0) as usize / item_height).saturating_sub(BUFFER);
+    let visible = (height as usize).div_ceil(item_height) + 2 * BUFFER;
+    let end = (first + visible).min(count);
+
+    let rows = (first..end).fold(
+        Node::native("div").with_attribute(
+            "style",
+            format!(
+                "position: absolute; top: {}px; left: 0; right: 0",
+                first * item_height
+            ),
+        ),
+        |rows, i| {
+            rows.with_child(
+                Node::native("div")
+                    .with_attribute("style", format!("height: {}px", item_height))
+                    .with_child(render(i)),
+            )
+        },
+    );
+
+    Node::native("div")
+        .with_attribute("style", format!("overflow-y: auto; height: {}px", height))
+        .with_child(
+            Node::native("div")
+                .with_attribute(
+                    "style",
+                    format!("position: relative; height: {}px", count * item_height),
+                )
+                .with_child(rows),
+        )
+        .on_scroll(on_scroll)
+}
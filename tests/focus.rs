@@ -0,0 +1,61 @@
+//! Runs in a headless browser: `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use slick::{run_with_config, BatchMode, Component, Effect, Node, RuntimeConfig};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::HtmlInputElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Profile {
+    saved: u32,
+}
+
+#[derive(Debug)]
+struct Saved;
+
+impl Component for Profile {
+    type Message = Saved;
+
+    fn view(&self) -> Node {
+        Node::native("div")
+            .with_child(Node::native("input").id("name").with_value("Ada Lovelace"))
+            .with_child(Node::native("p").with_child(Node::text_of(self.saved)))
+    }
+
+    fn update(&mut self, _: Saved) -> Option<Effect> {
+        self.saved += 1;
+        None
+    }
+}
+
+#[wasm_bindgen_test]
+fn focus_and_caret_survive_an_unrelated_change() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    root.set_id("profile");
+    document.body().unwrap().append_child(&root).unwrap();
+
+    let config = RuntimeConfig {
+        batch_mode: BatchMode::Immediate,
+    };
+    let dispatcher = run_with_config(Profile::default(), "#profile", config);
+
+    let input: HtmlInputElement = document
+        .get_element_by_id("name")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+    input.focus().unwrap();
+    input.set_selection_range(4, 8).unwrap();
+
+    dispatcher.dispatch(Saved);
+
+    assert_eq!(root.text_content().unwrap(), "1");
+    let active = document.active_element().unwrap();
+    assert!(active.is_same_node(Some(&input)));
+    assert_eq!(input.selection_start().unwrap(), Some(4));
+    assert_eq!(input.selection_end().unwrap(), Some(8));
+}